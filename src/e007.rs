@@ -97,6 +97,29 @@ pub fn add(a: f64, b: f64) -> f64 {
     a + b
 }
 
+/// Another trivial function, so we have something to compare `add` against.
+pub fn subtract(a: f64, b: f64) -> f64 {
+    a - b
+}
+
+/// Yet another trivial function for benchmarking.
+pub fn multiply(a: f64, b: f64) -> f64 {
+    a * b
+}
+
+/// A slightly less trivial function: this one can fail!
+///
+/// Dividing a floating point number by zero doesn't panic in Rust; it gives
+/// you infinity (or NaN, for `0.0 / 0.0`). Here we treat that as an error
+/// instead, and return a `Result` so the caller has to deal with it.
+pub fn divide(a: f64, b: f64) -> Result<f64, String> {
+    if b == 0.0 {
+        Err(format!("cannot divide {} by zero", a))
+    } else {
+        Ok(a / b)
+    }
+}
+
 /// A trivial test of a trivial function, demonstrating `#[test]`.
 ///
 /// This test function will not be compiled into a binary; it will *only* be
@@ -126,7 +149,7 @@ mod tests {
 
     // We'll use this for demonstrating benchmarks later.
     use std::thread::sleep;
-    use std::time::Duration;
+    use std::time::{Duration, Instant};
 
     /// Another, equally trivial, test, this one for `#[should_panic]`.
     ///
@@ -139,6 +162,155 @@ mod tests {
         assert_eq!(add(2.0, 2.0), 5.0);
     }
 
+    /// A table-driven test: one test function, many cases.
+    ///
+    /// Rather than writing a new `#[test]` function for every pair of inputs,
+    /// we can build a slice of `(lhs, rhs, expected)` tuples and loop over it.
+    /// The custom message on `assert_eq!` means that when a case fails, the
+    /// panic tells us *which* tuple was the problem---otherwise we'd just get
+    /// the two mismatched values and have to go hunting.
+    #[test]
+    fn test_add_cases() {
+        let cases: &[(f64, f64, f64)] = &[
+            (2.0, 2.0, 4.0),
+            (0.0, 0.0, 0.0),
+            (0.0, 5.0, 5.0),
+            (-1.0, 1.0, 0.0),
+            (-2.5, -2.5, -5.0),
+            (10.0, -20.0, -10.0),
+            // We can't include a NaN case like this one: NaN is not equal to
+            // *anything*, including itself, so `assert_eq!(NAN, NAN)` fails.
+            // To test for NaN, you have to use `f64::is_nan` instead.
+            //
+            // (::std::f64::NAN, 1.0, ::std::f64::NAN),
+        ];
+
+        for &(lhs, rhs, expected) in cases {
+            assert_eq!(
+                add(lhs, rhs),
+                expected,
+                "failed case: {:?}",
+                (lhs, rhs, expected)
+            );
+        }
+    }
+
+    /// A custom assertion macro: `assert_add_eq!(2.0, 2.0 => 4.0)`.
+    ///
+    /// We can write our own assertions just like `assert_eq!` using
+    /// `macro_rules!`. A couple things to notice about hygiene:
+    ///
+    ///   - The `lhs`, `rhs`, `expected`, and `actual` bindings inside the macro
+    ///     can't collide with variables of the same names at the call site:
+    ///     `macro_rules!` keeps local variables it introduces separate from the
+    ///     caller's.
+    ///   - That protection does *not* extend to items like functions, which
+    ///     are looked up where the macro is *used*. So we write the full path,
+    ///     `$crate::e007::add`, rather than just `add`, so the macro works even
+    ///     when the caller hasn't imported `add` (or has a different `add`!).
+    ///   - We bind each argument to a local exactly once, so an expression with
+    ///     side effects doesn't get evaluated twice when we print the message.
+    macro_rules! assert_add_eq {
+        ($lhs:expr, $rhs:expr => $expected:expr) => {{
+            let lhs = $lhs;
+            let rhs = $rhs;
+            let expected = $expected;
+            let actual = $crate::e007::add(lhs, rhs);
+            assert!(
+                actual == expected,
+                "expected {} + {} to be {}, but it was {}",
+                lhs,
+                rhs,
+                expected,
+                actual
+            );
+        }};
+    }
+
+    /// Using our custom assertion macro.
+    #[test]
+    fn test_add_with_macro() {
+        assert_add_eq!(2.0, 2.0 => 4.0);
+        assert_add_eq!(-1.0, 1.0 => 0.0);
+
+        // Hygiene: this `actual` is distinct from the one inside the macro.
+        let actual = 3.0;
+        assert_add_eq!(actual, 1.5 => 4.5);
+    }
+
+    /// A tiny linear congruential generator, for producing "random" test input.
+    ///
+    /// This is *not* good randomness---but it's deterministic (the same seed
+    /// gives the same sequence every time), which is exactly what we want for
+    /// a test that has to be reproducible. The constants are Knuth's MMIX ones.
+    struct Lcg(u64);
+
+    impl Lcg {
+        /// Produce the next value, scaled into the range `[-1000.0, 1000.0)`.
+        fn next_f64(&mut self) -> f64 {
+            self.0 = self
+                .0
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            // Use the top 53 bits, which is all the precision an `f64` has.
+            let unit = (self.0 >> 11) as f64 / (1u64 << 53) as f64;
+            unit * 2000.0 - 1000.0
+        }
+    }
+
+    /// A property test, without any external crates.
+    ///
+    /// Instead of checking specific inputs against specific outputs, we check
+    /// that a *property* holds for lots of inputs: here, that `add` is
+    /// commutative. A crate like [quickcheck] does this much more thoroughly:
+    /// it generates inputs for you (via its `Arbitrary` trait) from a real
+    /// random number generator, runs hundreds of cases, and when it finds a
+    /// failure, "shrinks" the input down to the simplest case that still fails.
+    ///
+    /// [quickcheck]: https://crates.io/crates/quickcheck
+    #[test]
+    fn test_add_is_commutative() {
+        let mut rng = Lcg(42);
+        for _ in 0..1000 {
+            let a = rng.next_f64();
+            let b = rng.next_f64();
+            assert_eq!(add(a, b), add(b, a), "not commutative for {:?}", (a, b));
+        }
+    }
+
+    /// Assert that two floating point numbers are within `epsilon` of each
+    /// other.
+    ///
+    /// Note that this is just a plain function, not a test: it doesn't have
+    /// the `#[test]` attribute, so the test runner won't call it directly.
+    fn assert_close(actual: f64, expected: f64, epsilon: f64) {
+        assert!(
+            (actual - expected).abs() < epsilon,
+            "{} is not within {} of {}",
+            actual,
+            epsilon,
+            expected
+        );
+    }
+
+    /// Why you shouldn't use `assert_eq!` with floating point numbers.
+    ///
+    /// `0.1` can't be represented exactly in binary floating point---the nearest
+    /// `f64` is very slightly off---so adding it to itself ten times
+    /// accumulates that tiny error, and the result is `0.9999999999999999`
+    /// rather than `1.0`. An `assert_eq!` here would fail; checking that the
+    /// result is *close enough* is the right approach.
+    #[test]
+    fn test_add_accumulated() {
+        let mut total = 0.0;
+        for _ in 0..10 {
+            total = add(total, 0.1);
+        }
+
+        assert_ne!(total, 1.0);
+        assert_close(total, 1.0, 1e-9);
+    }
+
     /// A yet more sophisticated example: `#[should_panic]` with `expected`.
     ///
     /// As the Rust book comments:
@@ -162,6 +334,31 @@ mod tests {
         bencher.iter(|| add(2.0, 2.0));
     }
 
+    /// Benchmark subtraction, for comparison with addition.
+    #[bench]
+    fn demonstrate_benchmarking_subtract(bencher: &mut Bencher) {
+        bencher.iter(|| subtract(2.0, 2.0));
+    }
+
+    /// Benchmark multiplication, for comparison with addition.
+    #[bench]
+    fn demonstrate_benchmarking_multiply(bencher: &mut Bencher) {
+        bencher.iter(|| multiply(2.0, 2.0));
+    }
+
+    /// Benchmark division, which has to do a check and build a `Result`.
+    #[bench]
+    fn demonstrate_benchmarking_divide(bencher: &mut Bencher) {
+        bencher.iter(|| divide(2.0, 2.0));
+    }
+
+    /// Division by zero gives us an `Err` rather than infinity.
+    #[test]
+    fn test_divide_by_zero() {
+        assert!(divide(1.0, 0.0).is_err());
+        assert_eq!(divide(1.0, 2.0), Ok(0.5));
+    }
+
     /// We can also have secondary functions used to help with testing.
     ///
     /// This particular function is *stupid*; the way to do this, of course, is
@@ -173,6 +370,25 @@ mod tests {
         Duration::new(0, ns)
     }
 
+    /// An expensive test, demonstrating `#[ignore]`.
+    ///
+    /// Sometimes you have tests which are slow---they hit the network, or the
+    /// disk, or (as here) just do a *lot* of work. You don't want to run those
+    /// every single time you run `cargo test`, but you don't want to delete
+    /// them either. Marking them with `#[ignore]` means a plain `cargo test`
+    /// will skip them (and report them as "ignored"). When you *do* want to
+    /// run them, use `cargo test -- --ignored`.
+    #[test]
+    #[ignore]
+    fn expensive_test() {
+        let mut total = Duration::new(0, 0);
+        for _ in 0..5_000_000 {
+            total += support_function(1);
+        }
+
+        assert!(total > Duration::new(0, 0));
+    }
+
     /// Benchmark a function that sleeps for 1ms every time you call it.
     ///
     /// One of the things this highlights: we have a *tiny* duration (10 ns)...
@@ -183,4 +399,56 @@ mod tests {
         let duration = support_function(10);
         bencher.iter(|| sleep(duration));
     }
+
+    /// A summary of the timings from a set of runs, like the benchmarker's.
+    #[derive(Debug)]
+    struct BenchSummary {
+        min: Duration,
+        max: Duration,
+        mean: Duration,
+        stddev: Duration,
+    }
+
+    /// Run a closure `runs` times and summarize how long each run took.
+    ///
+    /// This is a (much!) simpler version of what the `Bencher` does for us.
+    /// The point is to make the `+/-` in the benchmark output less mysterious:
+    /// every run takes a slightly different amount of time, and the spread of
+    /// those times is what the `+/-` is reporting.
+    fn bench_stats<F: FnMut()>(runs: usize, mut f: F) -> BenchSummary {
+        let timings: Vec<Duration> = (0..runs)
+            .map(|_| {
+                let start = Instant::now();
+                f();
+                start.elapsed()
+            })
+            .collect();
+
+        let nanos: Vec<f64> = timings.iter().map(|t| t.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / runs as f64;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / runs as f64;
+
+        BenchSummary {
+            min: *timings.iter().min().expect("at least one run"),
+            max: *timings.iter().max().expect("at least one run"),
+            mean: Duration::from_nanos(mean as u64),
+            stddev: Duration::from_nanos(variance.sqrt() as u64),
+        }
+    }
+
+    /// See the variance for ourselves, using the same sleep as above.
+    ///
+    /// Run this with `cargo test -- --nocapture` to see the printed summary.
+    /// Even though we only ask to sleep for 10 ns, each run takes *far* longer
+    /// than that, and no two runs take quite the same amount of time.
+    #[test]
+    fn demonstrate_bench_stats_with_sleep() {
+        let duration = support_function(10);
+        let summary = bench_stats(20, || sleep(duration));
+        println!("{:?}", summary);
+
+        assert!(summary.min <= summary.mean);
+        assert!(summary.mean <= summary.max);
+        assert!(summary.stddev <= summary.max - summary.min);
+    }
 }
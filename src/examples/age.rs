@@ -0,0 +1,78 @@
+//! Fallible conversions with `TryFrom`.
+//!
+//! `From` is for conversions which always succeed: every `u8` fits in a
+//! `u32`, so `u32: From<u8>`, and its `from` returns a plain `u32`. `TryFrom`
+//! is for conversions which might not: its `try_from` returns a `Result`, with
+//! an associated `Error` type to say what went wrong. Implementing either one
+//! gets you the matching `into` or `try_into` method on the source type for
+//! free.
+
+use std::convert::TryFrom;
+
+/// An age in years, which is guaranteed to be plausible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Age(u8);
+
+impl Age {
+    /// The oldest age we'll accept.
+    pub const MAX: u8 = 150;
+
+    /// Get the age as a number.
+    pub fn years(self) -> u8 {
+        self.0
+    }
+}
+
+impl TryFrom<i32> for Age {
+    type Error = String;
+
+    fn try_from(years: i32) -> Result<Self, Self::Error> {
+        if years < 0 {
+            Err(format!("age cannot be negative (got {})", years))
+        } else if years > i32::from(Age::MAX) {
+            Err(format!(
+                "age cannot be more than {} (got {})",
+                Age::MAX,
+                years
+            ))
+        } else {
+            // The checks above make this cast lossless.
+            Ok(Age(years as u8))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryInto;
+
+    #[test]
+    fn valid() {
+        assert_eq!(Age::try_from(0).map(Age::years), Ok(0));
+        assert_eq!(Age::try_from(42).map(Age::years), Ok(42));
+        assert_eq!(Age::try_from(150).map(Age::years), Ok(150));
+    }
+
+    #[test]
+    fn negative() {
+        assert_eq!(
+            Age::try_from(-1),
+            Err(String::from("age cannot be negative (got -1)"))
+        );
+    }
+
+    #[test]
+    fn too_old() {
+        assert_eq!(
+            Age::try_from(151),
+            Err(String::from("age cannot be more than 150 (got 151)"))
+        );
+    }
+
+    #[test]
+    fn try_into() {
+        let age: Result<Age, _> = 30.try_into();
+        assert_eq!(age, Ok(Age(30)));
+    }
+}
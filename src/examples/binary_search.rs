@@ -0,0 +1,60 @@
+//! Binary search, and what its `Err` means.
+
+/// Find `target` in `sorted`: `Ok` with its index if it's there, or `Err` with
+/// the index where it *would* go if it isn't.
+///
+/// This is just `slice::binary_search`, but the contract is worth spelling
+/// out. The `Err` isn't a failure so much as a different answer: inserting
+/// `target` at that index (with `Vec::insert`) keeps the slice sorted. So
+/// `Err(0)` means "smaller than everything", and `Err(sorted.len())` means
+/// "bigger than everything". If `target` appears more than once, the `Ok`
+/// index could be any one of them.
+///
+/// The slice *must* already be sorted in ascending order. That isn't checked
+/// (doing so would take O(n) time, throwing away the point of an O(log n)
+/// search), and on unsorted input the result is meaningless---though never
+/// out of bounds, and never a panic.
+pub fn find_or_insert_pos(sorted: &[i32], target: i32) -> Result<usize, usize> {
+    sorted.binary_search(&target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SORTED: [i32; 5] = [1, 3, 5, 7, 9];
+
+    #[test]
+    fn found() {
+        assert_eq!(find_or_insert_pos(&SORTED, 1), Ok(0));
+        assert_eq!(find_or_insert_pos(&SORTED, 7), Ok(3));
+        assert_eq!(find_or_insert_pos(&SORTED, 9), Ok(4));
+    }
+
+    #[test]
+    fn missing_from_the_middle() {
+        assert_eq!(find_or_insert_pos(&SORTED, 4), Err(2));
+    }
+
+    #[test]
+    fn before_the_start() {
+        assert_eq!(find_or_insert_pos(&SORTED, -10), Err(0));
+    }
+
+    #[test]
+    fn after_the_end() {
+        assert_eq!(find_or_insert_pos(&SORTED, 10), Err(5));
+        assert_eq!(find_or_insert_pos(&[], 10), Err(0));
+    }
+
+    #[test]
+    fn insertion_keeps_order() {
+        let mut data = SORTED.to_vec();
+        for &target in &[6, 0, 12, 3] {
+            if let Err(position) = find_or_insert_pos(&data, target) {
+                data.insert(position, target);
+            }
+        }
+        assert_eq!(data, vec![0, 1, 3, 5, 6, 7, 9, 12]);
+    }
+}
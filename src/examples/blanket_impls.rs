@@ -0,0 +1,48 @@
+//! Blanket implementations: one `impl` for every type with a given trait.
+//!
+//! `impl<T: Display> Printable for T` implements `Printable` for *every*
+//! `Display` type at once---including ones from the standard library, or from
+//! other crates, which we could never write individual impls for as easily. The
+//! standard library does this all over the place: `ToString` is implemented
+//! for every `T: Display`, which is why `42.to_string()` works, and every
+//! `T: From<U>` gets a matching `U: Into<T>`.
+//!
+//! The catch is that a blanket impl rules out any other overlapping impl: we
+//! can't *also* write a special `impl Printable for i32`, since `i32` is
+//! already covered.
+
+use std::fmt::Display;
+
+/// Something which can describe itself for printing.
+pub trait Printable {
+    /// The text to print. (We return it rather than printing it directly, so
+    /// it can be tested.)
+    fn print_me(&self) -> String;
+}
+
+impl<T: Display> Printable for T {
+    fn print_me(&self) -> String {
+        format!("Printing: {}", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer() {
+        assert_eq!(42.print_me(), "Printing: 42");
+    }
+
+    #[test]
+    fn string() {
+        assert_eq!(String::from("hello").print_me(), "Printing: hello");
+        assert_eq!("a slice, too".print_me(), "Printing: a slice, too");
+    }
+
+    #[test]
+    fn float() {
+        assert_eq!(2.5_f64.print_me(), "Printing: 2.5");
+    }
+}
@@ -0,0 +1,119 @@
+//! The builder pattern, with defaults.
+//!
+//! Rust doesn't have default or named function arguments, so once a struct
+//! has more than a couple fields, a *builder* is the usual way to let callers
+//! set only the fields they care about.
+
+/// Configuration for a (hypothetical) server.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServerConfig {
+    pub host: String,
+    pub port: u16,
+    pub timeout_ms: u64,
+    pub retries: u8,
+}
+
+impl Default for ServerConfig {
+    fn default() -> ServerConfig {
+        ServerConfig {
+            host: String::from("localhost"),
+            port: 8080,
+            timeout_ms: 30_000,
+            retries: 3,
+        }
+    }
+}
+
+/// A builder for `ServerConfig`.
+///
+/// Every field starts out as `None`, which is exactly what `#[derive(Default)]`
+/// gives us; `build` then fills in anything still unset from
+/// `ServerConfig::default()`.
+///
+/// This builder takes `self` *by value* in each method and hands it back,
+/// which makes one-shot chains read nicely:
+/// `ServerConfigBuilder::new().port(80).build()`. The alternative is to take
+/// `&mut self` and return `&mut Self`. That version is nicer when you want to
+/// configure the builder conditionally, in several statements (no need to
+/// reassign `builder = builder.port(80)` each time), but then `build` can
+/// only borrow the builder, so it has to clone the fields out of it.
+#[derive(Debug, Default)]
+pub struct ServerConfigBuilder {
+    host: Option<String>,
+    port: Option<u16>,
+    timeout_ms: Option<u64>,
+    retries: Option<u8>,
+}
+
+impl ServerConfigBuilder {
+    pub fn new() -> ServerConfigBuilder {
+        ServerConfigBuilder::default()
+    }
+
+    pub fn host(mut self, host: &str) -> ServerConfigBuilder {
+        self.host = Some(host.to_string());
+        self
+    }
+
+    pub fn port(mut self, port: u16) -> ServerConfigBuilder {
+        self.port = Some(port);
+        self
+    }
+
+    pub fn timeout_ms(mut self, timeout_ms: u64) -> ServerConfigBuilder {
+        self.timeout_ms = Some(timeout_ms);
+        self
+    }
+
+    pub fn retries(mut self, retries: u8) -> ServerConfigBuilder {
+        self.retries = Some(retries);
+        self
+    }
+
+    pub fn build(self) -> ServerConfig {
+        let defaults = ServerConfig::default();
+        ServerConfig {
+            host: self.host.unwrap_or(defaults.host),
+            port: self.port.unwrap_or(defaults.port),
+            timeout_ms: self.timeout_ms.unwrap_or(defaults.timeout_ms),
+            retries: self.retries.unwrap_or(defaults.retries),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fully_specified() {
+        let config = ServerConfigBuilder::new()
+            .host("example.com")
+            .port(443)
+            .timeout_ms(5_000)
+            .retries(0)
+            .build();
+
+        assert_eq!(
+            config,
+            ServerConfig {
+                host: String::from("example.com"),
+                port: 443,
+                timeout_ms: 5_000,
+                retries: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn defaults_only() {
+        assert_eq!(ServerConfigBuilder::new().build(), ServerConfig::default());
+    }
+
+    #[test]
+    fn partially_specified() {
+        let config = ServerConfigBuilder::new().port(9000).build();
+        assert_eq!(config.port, 9000);
+        assert_eq!(config.host, "localhost");
+    }
+}
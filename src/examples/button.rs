@@ -0,0 +1,66 @@
+//! Storing a closure in a struct with `Box<dyn Fn>`.
+//!
+//! Every closure has its own anonymous type, so a struct field can't name the
+//! type of the closure it holds. There are two ways around that. We could make
+//! the struct generic, `Button<F: Fn() -> String>`, but then every button with
+//! a different handler is a different type, and we couldn't keep them together
+//! in a `Vec`. Or we can put the closure behind a pointer to a trait object,
+//! as here: `Box<dyn Fn() -> String>` is one concrete type, whatever closure
+//! is inside it, at the cost of an allocation and a dynamic call. See [e025]
+//! for more on closures and `dyn Trait`, and the [`closures`] example for
+//! *returning* closures.
+//!
+//! [e025]: /show_notes/e025/index.html
+//! [`closures`]: ../closures/index.html
+
+/// A button which runs a handler when it's clicked.
+pub struct Button {
+    on_click: Box<dyn Fn() -> String>,
+}
+
+impl Button {
+    /// Create a button which calls `f` when it's clicked.
+    ///
+    /// A `Box<dyn Fn() -> String>` field is really `Box<dyn Fn() -> String +
+    /// 'static>`: with no lifetime given, a boxed trait object defaults to
+    /// `'static`. So `F` has to be `'static` too, meaning the closure can't
+    /// hold on to any borrowed data which might go away while the button
+    /// still exists. In practice, that means capturing by `move`, as in the
+    /// tests below. (To allow borrowing closures, the struct would need a
+    /// lifetime parameter: `Box<dyn Fn() -> String + 'a>`.)
+    pub fn new<F: Fn() -> String + 'static>(f: F) -> Button {
+        Button {
+            on_click: Box::new(f),
+        }
+    }
+
+    /// Click the button, returning whatever the handler does.
+    pub fn click(&self) -> String {
+        (self.on_click)()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn captures_a_value() {
+        let name = String::from("Submit");
+        let button = Button::new(move || format!("{} clicked!", name));
+        assert_eq!(button.click(), "Submit clicked!");
+        // `Fn`, so we can click as often as we like.
+        assert_eq!(button.click(), "Submit clicked!");
+    }
+
+    #[test]
+    fn different_closures_same_type() {
+        let id = 7;
+        let buttons = [
+            Button::new(|| String::from("ok")),
+            Button::new(move || format!("button #{}", id)),
+        ];
+        let clicks: Vec<String> = buttons.iter().map(Button::click).collect();
+        assert_eq!(clicks, vec!["ok", "button #7"]);
+    }
+}
@@ -0,0 +1,139 @@
+//! Designing a custom error type.
+//!
+//! A good error type in Rust is usually an `enum` with one variant per kind of
+//! failure, which implements `Display` (so it can be shown to a person),
+//! `Error` (so it plays nicely with everything else that handles errors), and
+//! `From` for any underlying errors it wraps (so `?` can convert them for us).
+
+use std::error::Error;
+use std::fmt;
+use std::num::ParseFloatError;
+
+/// Everything that can go wrong in `safe_divide`.
+#[derive(Debug, PartialEq)]
+pub enum CalcError {
+    DivideByZero,
+    Overflow,
+    Parse(ParseFloatError),
+    /// The input parsed, but as infinity or NaN, which we don't calculate with.
+    NotFinite(String),
+}
+
+impl fmt::Display for CalcError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CalcError::DivideByZero => write!(f, "cannot divide by zero"),
+            CalcError::Overflow => write!(f, "result is too large to represent"),
+            CalcError::Parse(err) => write!(f, "could not parse number: {}", err),
+            CalcError::NotFinite(input) => write!(f, "not a finite number: {:?}", input),
+        }
+    }
+}
+
+impl Error for CalcError {
+    /// Only the `Parse` variant has an underlying cause we can point to.
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CalcError::Parse(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// This is what lets `?` turn a `ParseFloatError` into a `CalcError`.
+impl From<ParseFloatError> for CalcError {
+    fn from(err: ParseFloatError) -> CalcError {
+        CalcError::Parse(err)
+    }
+}
+
+/// Parse `input` as a finite number.
+///
+/// `f64`'s `FromStr` happily accepts `"inf"`, `"-infinity"`, and `"NaN"`. Left
+/// alone, an infinite input would later be reported as an `Overflow`, and a
+/// NaN would sail straight through the calculation, so we turn both away here.
+fn parse_finite(input: &str) -> Result<f64, CalcError> {
+    let input = input.trim();
+    let number: f64 = input.parse()?;
+    if number.is_finite() {
+        Ok(number)
+    } else {
+        Err(CalcError::NotFinite(input.to_string()))
+    }
+}
+
+/// Parse two strings as numbers and divide the first by the second.
+pub fn safe_divide(a: &str, b: &str) -> Result<f64, CalcError> {
+    let a = parse_finite(a)?;
+    let b = parse_finite(b)?;
+
+    if b == 0.0 {
+        return Err(CalcError::DivideByZero);
+    }
+
+    let result = a / b;
+    if result.is_infinite() {
+        Err(CalcError::Overflow)
+    } else {
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn divides() {
+        assert_eq!(safe_divide("10", "4"), Ok(2.5));
+        assert_eq!(safe_divide(" -9 ", "3"), Ok(-3.0));
+    }
+
+    #[test]
+    fn divide_by_zero() {
+        let err = safe_divide("1", "0").unwrap_err();
+        assert_eq!(err, CalcError::DivideByZero);
+        assert_eq!(err.to_string(), "cannot divide by zero");
+    }
+
+    #[test]
+    fn overflow() {
+        let err = safe_divide("1e308", "1e-308").unwrap_err();
+        assert_eq!(err, CalcError::Overflow);
+        assert!(err.source().is_none());
+    }
+
+    #[test]
+    fn infinite_input() {
+        let err = safe_divide("inf", "2").unwrap_err();
+        assert_eq!(err, CalcError::NotFinite(String::from("inf")));
+        assert_eq!(err.to_string(), "not a finite number: \"inf\"");
+        assert_eq!(
+            safe_divide("1", " -infinity "),
+            Err(CalcError::NotFinite(String::from("-infinity")))
+        );
+    }
+
+    #[test]
+    fn nan_input() {
+        assert_eq!(
+            safe_divide("NaN", "2"),
+            Err(CalcError::NotFinite(String::from("NaN")))
+        );
+        assert_eq!(
+            safe_divide("2", "nan"),
+            Err(CalcError::NotFinite(String::from("nan")))
+        );
+    }
+
+    #[test]
+    fn parse_failure() {
+        let err = safe_divide("one", "2").unwrap_err();
+        match err {
+            CalcError::Parse(_) => {}
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+        assert!(err.source().is_some());
+        assert!(err.to_string().starts_with("could not parse number"));
+    }
+}
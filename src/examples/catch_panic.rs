@@ -0,0 +1,72 @@
+//! Recovering from a panic with `std::panic::catch_unwind`.
+//!
+//! [e007]'s `#[should_panic]` tests rely on the test harness catching the panic
+//! for us. `catch_unwind` is how to do the same thing ourselves: it runs a
+//! closure, and if the closure panics, it stops the unwinding and hands back an
+//! `Err` instead of letting it take down the thread. It's *not* a general
+//! error-handling mechanism---that's what `Result` is for---but it's the right
+//! tool at boundaries: a thread pool keeping a worker alive, or an FFI function
+//! which must not let a panic unwind into C code.
+//!
+//! [e007]: /show_notes/e007/index.html
+
+use std::any::Any;
+use std::panic::{self, UnwindSafe};
+
+/// Run `f`, converting a panic into an `Err` holding the panic message.
+///
+/// The `UnwindSafe` bound is about what a panic might leave behind. If `f`
+/// panics halfway through mutating something it borrowed, that something
+/// could be left in a broken state, and we'd then carry on using it after
+/// catching the panic. So by default, closures which capture `&mut`
+/// references (or `RefCell`s, and similar) aren't `UnwindSafe`, and the
+/// compiler won't let us pass them in. If you've checked that a broken state
+/// is impossible (or harmless), wrapping the closure in `AssertUnwindSafe`
+/// overrides the check.
+pub fn run_safely<F: FnOnce() -> T + UnwindSafe, T>(f: F) -> Result<T, String> {
+    panic::catch_unwind(f).map_err(|payload| panic_message(&*payload))
+}
+
+/// Extract the message from a panic payload.
+///
+/// A panic can carry any value at all, as a `Box<dyn Any + Send>`, so we have
+/// to guess its type. In practice, it's almost always one of two: `panic!`
+/// with just a string literal gives a `&'static str`, and `panic!` with
+/// format arguments gives a `String`.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("<non-string panic payload>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normal_closure() {
+        assert_eq!(run_safely(|| 2 + 2), Ok(4));
+    }
+
+    #[test]
+    fn panicking_closure() {
+        let result: Result<(), String> = run_safely(|| panic!("Crazed monkeys!"));
+        assert_eq!(result, Err(String::from("Crazed monkeys!")));
+    }
+
+    #[test]
+    fn formatted_panic() {
+        let result: Result<(), String> = run_safely(|| panic!("{} crazed monkeys!", 3));
+        assert_eq!(result, Err(String::from("3 crazed monkeys!")));
+    }
+
+    #[test]
+    fn non_string_payload() {
+        let result: Result<(), String> = run_safely(|| panic::panic_any(42));
+        assert_eq!(result, Err(String::from("<non-string panic payload>")));
+    }
+}
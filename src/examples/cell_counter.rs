@@ -0,0 +1,63 @@
+//! Interior mutability for `Copy` values with `Cell`.
+//!
+//! `Cell` and `RefCell` both let us mutate through a shared reference, but
+//! they make different trades. `RefCell` hands out references to its contents,
+//! so it has to track them at runtime, and panics if a `borrow_mut` overlaps
+//! another borrow. `Cell` never hands out a reference at all: `get` copies the
+//! value out, and `set` replaces it wholesale. With no references, there's
+//! nothing to track and nothing which can conflict, so `Cell` has no runtime
+//! checks and can't panic. The catch is that `get` only works for `Copy`
+//! types. So for a counter, a flag, or any other small `Copy` value, `Cell` is
+//! the simpler and cheaper choice; for a `String` or a `Vec` which you want to
+//! change in place, it's `RefCell`. (Neither is `Sync`; across threads, the
+//! equivalents are atomics and `Mutex`.)
+
+use std::cell::Cell;
+
+/// A counter which can be bumped through a shared reference.
+#[derive(Debug, Default)]
+pub struct Counter {
+    count: Cell<u32>,
+}
+
+impl Counter {
+    pub fn new() -> Counter {
+        Counter::default()
+    }
+
+    /// Add one to the count. Note `&self`, not `&mut self`.
+    pub fn increment(&self) {
+        self.count.set(self.count.get() + 1);
+    }
+
+    /// The current count.
+    pub fn get(&self) -> u32 {
+        self.count.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn increments_through_a_shared_reference() {
+        // No `mut` anywhere.
+        let counter = Counter::new();
+        assert_eq!(counter.get(), 0);
+        counter.increment();
+        counter.increment();
+        assert_eq!(counter.get(), 2);
+    }
+
+    #[test]
+    fn many_shared_references_at_once() {
+        let counter = Counter::new();
+        let a = &counter;
+        let b = &counter;
+        a.increment();
+        b.increment();
+        a.increment();
+        assert_eq!(counter.get(), 3);
+    }
+}
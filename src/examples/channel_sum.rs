@@ -0,0 +1,48 @@
+//! Message passing with `std::sync::mpsc` channels.
+//!
+//! Instead of sharing memory and locking it, threads can communicate by
+//! sending values down a channel. "mpsc" stands for "multiple producer, single
+//! consumer": you can clone the `Sender` as many times as you like, but there's
+//! only ever one `Receiver`.
+
+use std::sync::mpsc;
+use std::thread;
+
+/// Send each number from a producer thread, and sum them on this thread.
+///
+/// The subtle part is how the consumer knows when to stop. Iterating over the
+/// `Receiver` blocks waiting for the next value, and only ends once *every*
+/// `Sender` has been dropped. Here, `tx` is moved into the producer thread, so
+/// it's dropped when that thread finishes sending, and our loop ends. If we
+/// kept a `Sender` alive on this thread (say, by cloning `tx` before moving
+/// it), the loop would wait forever for a message which would never come.
+pub fn sum_via_channel(numbers: Vec<i32>) -> i32 {
+    let (tx, rx) = mpsc::channel();
+
+    let producer = thread::spawn(move || {
+        for n in numbers {
+            tx.send(n).expect("the receiver is still alive");
+        }
+        // `tx` is dropped here, which closes the channel.
+    });
+
+    let sum = rx.iter().sum();
+    producer.join().unwrap();
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sums_numbers() {
+        assert_eq!(sum_via_channel(vec![1, 2, 3, 4, 5]), 15);
+        assert_eq!(sum_via_channel(vec![-10, 4, 6]), 0);
+    }
+
+    #[test]
+    fn empty_does_not_deadlock() {
+        assert_eq!(sum_via_channel(Vec::new()), 0);
+    }
+}
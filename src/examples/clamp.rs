@@ -0,0 +1,67 @@
+//! Generic clamping with `PartialOrd`.
+
+/// Restrict `value` to the range `lo..=hi`.
+///
+/// Panics if `lo > hi`---or, for floats, if either bound is NaN, since then
+/// `lo <= hi` is false too. Without that check, a backward range would quietly
+/// give different answers depending on which comparison happened first.
+///
+/// The standard library has `Ord::clamp` (and `f32::clamp` and `f64::clamp`)
+/// with the same behavior, including the panic. The difference is the bound:
+/// `Ord` requires a *total* order, which floats don't have, precisely because
+/// of NaN. Asking only for `PartialOrd` means this one function works for
+/// integers, floats, strings, and anything else comparable. The cost is that
+/// a NaN `value` fails both comparisons below, and so comes back unchanged.
+pub fn clamp_value<T: PartialOrd>(value: T, lo: T, hi: T) -> T {
+    assert!(lo <= hi, "clamp_value: lo must not be greater than hi");
+    if value < lo {
+        lo
+    } else if value > hi {
+        hi
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn below() {
+        assert_eq!(clamp_value(-5, 0, 10), 0);
+        assert_eq!(clamp_value(-0.5, 0.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn within() {
+        assert_eq!(clamp_value(5, 0, 10), 5);
+        assert_eq!(clamp_value(0, 0, 10), 0);
+        assert_eq!(clamp_value("m", "a", "z"), "m");
+    }
+
+    #[test]
+    fn above() {
+        assert_eq!(clamp_value(50, 0, 10), 10);
+        assert_eq!(clamp_value(1.5, 0.0, 1.0), 1.0);
+    }
+
+    #[test]
+    fn empty_range() {
+        assert_eq!(clamp_value(3, 7, 7), 7);
+        assert_eq!(clamp_value(9, 7, 7), 7);
+    }
+
+    #[test]
+    fn matches_ord_clamp() {
+        for value in -3..13 {
+            assert_eq!(clamp_value(value, 0, 10), value.clamp(0, 10));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "lo must not be greater than hi")]
+    fn backward_range() {
+        clamp_value(5, 10, 0);
+    }
+}
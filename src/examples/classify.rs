@@ -0,0 +1,57 @@
+//! Range patterns, match guards, and `matches!`.
+
+/// Describe the size of `n`.
+///
+/// A few pattern-matching features at work here:
+///
+///   - `1..=9` is an *inclusive* range pattern: it matches 1, 9, and
+///     everything in between. (Exclusive range patterns, `1..10`, were only
+///     stabilized much more recently; inclusive ones are still the norm.)
+///   - `n if n < 0` is a *match guard*: the arm only matches if the pattern
+///     does *and* the condition is true. Guards aren't considered when checking
+///     exhaustiveness: if we replaced the final `_` with `10..=i32::MAX`, the
+///     compiler would complain that negative numbers aren't covered, even
+///     though the guard handles them. (An `i32::MIN..=-1` range pattern would
+///     do the same job as the guard, without that caveat.)
+pub fn classify(n: i32) -> &'static str {
+    match n {
+        n if n < 0 => "negative",
+        0 => "zero",
+        1..=9 => "small",
+        _ => "large",
+    }
+}
+
+/// Is `c` a (lowercase or uppercase) English vowel?
+///
+/// `matches!` is shorthand for a `match` with one `true` arm and a `false`
+/// catch-all, and accepts the same patterns---including `|` for alternatives.
+pub fn is_vowel(c: char) -> bool {
+    matches!(c.to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn boundaries() {
+        assert_eq!(classify(i32::MIN), "negative");
+        assert_eq!(classify(-1), "negative");
+        assert_eq!(classify(0), "zero");
+        assert_eq!(classify(1), "small");
+        assert_eq!(classify(9), "small");
+        assert_eq!(classify(10), "large");
+        assert_eq!(classify(i32::MAX), "large");
+    }
+
+    #[test]
+    fn vowels() {
+        for c in "aeiouAEIOU".chars() {
+            assert!(is_vowel(c), "{} should be a vowel", c);
+        }
+        for c in "bcdxyzBXY1 !é".chars() {
+            assert!(!is_vowel(c), "{} should not be a vowel", c);
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//! Parsing command-line arguments, testably.
+//!
+//! The obvious way to write this is to call `std::env::args()` inside the
+//! parser. But then the only way to test it is to actually run a process with
+//! different arguments! Taking *any* iterator of `String`s instead means the
+//! real program can pass in `env::args().skip(1)` (skipping the program name),
+//! while tests can pass in whatever fake arguments they like.
+
+/// Split arguments into a `--verbose` flag and everything else.
+///
+/// Returns whether `--verbose` appeared anywhere, and all the other arguments
+/// in their original order. Unrecognized flags like `--force` aren't an error
+/// here; they're passed through with the positional arguments, for some other
+/// piece of code to deal with.
+pub fn parse_flags(args: impl Iterator<Item = String>) -> (bool, Vec<String>) {
+    let mut verbose = false;
+    let mut rest = Vec::new();
+    for arg in args {
+        if arg == "--verbose" {
+            verbose = true;
+        } else {
+            rest.push(arg);
+        }
+    }
+
+    (verbose, rest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> impl Iterator<Item = String> {
+        list.iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    #[test]
+    fn verbose_and_positional() {
+        let (verbose, rest) = parse_flags(args(&["input.txt", "--verbose", "output.txt"]));
+        assert!(verbose);
+        assert_eq!(rest, vec!["input.txt", "output.txt"]);
+    }
+
+    #[test]
+    fn not_verbose() {
+        let (verbose, rest) = parse_flags(args(&["input.txt"]));
+        assert!(!verbose);
+        assert_eq!(rest, vec!["input.txt"]);
+    }
+
+    #[test]
+    fn unknown_flags_pass_through() {
+        let (verbose, rest) = parse_flags(args(&["--force", "-v", "file"]));
+        assert!(!verbose);
+        assert_eq!(rest, vec!["--force", "-v", "file"]);
+    }
+
+    #[test]
+    fn no_args() {
+        let (verbose, rest) = parse_flags(args(&[]));
+        assert!(!verbose);
+        assert!(rest.is_empty());
+    }
+}
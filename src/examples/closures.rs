@@ -0,0 +1,64 @@
+//! Closures: returning them with `impl Trait`, and `Fn` vs. `FnMut`.
+//!
+//! Every closure has its own unique, unnameable type, so before `impl Trait`
+//! the only way to return one was to box it up (`Box<Fn(i32) -> i32>`). Now
+//! we can just say "some type which implements `Fn(i32) -> i32`" and let the
+//! compiler fill in the details---no allocation required.
+
+/// Get a closure which adds `n` to its argument.
+///
+/// The closure only *reads* `n`, so it implements `Fn`: you can call it as
+/// many times as you like, through a shared reference. The `move` is needed
+/// because otherwise the closure would borrow `n`, which is about to go out of
+/// scope when `make_adder` returns.
+pub fn make_adder(n: i32) -> impl Fn(i32) -> i32 {
+    move |x| x + n
+}
+
+/// Get a closure which counts up by one every time you call it.
+///
+/// Because the closure *mutates* its captured state, it only implements
+/// `FnMut`, not `Fn`. That means calling it requires a mutable binding: you'll
+/// need `let mut counter = make_counter();`, and you couldn't pass `counter`
+/// to a function expecting an `Fn`.
+pub fn make_counter() -> impl FnMut() -> u32 {
+    let mut count = 0;
+    move || {
+        count += 1;
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adder() {
+        assert_eq!(make_adder(5)(10), 15);
+
+        // An `Fn` can be called through a shared reference, repeatedly.
+        let add_two = make_adder(2);
+        let by_ref = &add_two;
+        assert_eq!(by_ref(1), 3);
+        assert_eq!(by_ref(1), 3);
+    }
+
+    #[test]
+    fn counter() {
+        let mut counter = make_counter();
+        assert_eq!(counter(), 1);
+        assert_eq!(counter(), 2);
+        assert_eq!(counter(), 3);
+    }
+
+    #[test]
+    fn counters_are_independent() {
+        let mut first = make_counter();
+        let mut second = make_counter();
+        first();
+        first();
+        assert_eq!(second(), 1);
+        assert_eq!(first(), 3);
+    }
+}
@@ -0,0 +1,64 @@
+//! Compile-time evaluation with `const fn`.
+//!
+//! A `const fn` can be called in a `const` context---a `const` or `static`
+//! initializer, an array length---and the compiler then runs it at compile
+//! time, baking the result into the binary. It's still an ordinary function
+//! at runtime, too.
+//!
+//! Not everything is allowed in a `const fn`. Arithmetic, `let` bindings
+//! (including `let mut`), `if`, `match`, `loop` and `while`, and calls to
+//! other `const fn`s are all fine. Heap allocation, trait methods (so no
+//! `for` loops, which use `Iterator`), and anything with side effects, like
+//! I/O, are not. And overflow, which would panic at runtime, is a compile
+//! error when it happens during constant evaluation.
+
+/// `n!`, computed iteratively.
+///
+/// Compile-time evaluation has limits of its own, so a loop is a safer bet
+/// than deep recursion here. `factorial(20)` is the largest which fits in a
+/// `u64`.
+pub const fn factorial(n: u64) -> u64 {
+    let mut result = 1;
+    let mut i = 2;
+    while i <= n {
+        result *= i;
+        i += 1;
+    }
+    result
+}
+
+/// The factorials of 0 through 9, computed at compile time.
+pub const TABLE: [u64; 10] = {
+    let mut table = [0; 10];
+    let mut i = 0;
+    while i < table.len() {
+        table[i] = factorial(i as u64);
+        i += 1;
+    }
+    table
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn factorials() {
+        assert_eq!(factorial(0), 1);
+        assert_eq!(factorial(1), 1);
+        assert_eq!(factorial(5), 120);
+        assert_eq!(factorial(20), 2_432_902_008_176_640_000);
+    }
+
+    #[test]
+    fn table_matches() {
+        assert_eq!(TABLE[5], factorial(5));
+        assert_eq!(TABLE, [1, 1, 2, 6, 24, 120, 720, 5040, 40320, 362_880]);
+    }
+
+    #[test]
+    fn usable_as_an_array_length() {
+        let array = [0_u8; factorial(3) as usize];
+        assert_eq!(array.len(), 6);
+    }
+}
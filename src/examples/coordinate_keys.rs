@@ -0,0 +1,65 @@
+//! Using your own type as a `HashMap` key.
+//!
+//! A `HashMap` key needs `Hash` (to pick a bucket) and `Eq` (to tell apart
+//! different keys which land in the same bucket). The two have to *agree*:
+//! if `a == b`, then `a` and `b` must hash to the same value. Otherwise a
+//! lookup with `b` would go to a different bucket from the one where `a` was
+//! stored, and miss it even though the keys are equal. Deriving both the
+//! traits guarantees they agree, since both are then based on the same set of
+//! fields; be careful when writing either one by hand.
+
+use std::collections::HashMap;
+
+/// A position on a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Coordinate {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Build a lookup table from coordinates to labels.
+///
+/// If the same coordinate appears more than once, the last label wins.
+pub fn label_grid(points: &[(Coordinate, &str)]) -> HashMap<Coordinate, String> {
+    points
+        .iter()
+        .map(|&(coordinate, label)| (coordinate, label.to_string()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash_of(coordinate: &Coordinate) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        coordinate.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn equal_coordinates_hash_equally() {
+        let a = Coordinate { x: 3, y: -4 };
+        let b = Coordinate { x: 3, y: -4 };
+        assert_eq!(a, b);
+        assert_eq!(hash_of(&a), hash_of(&b));
+    }
+
+    #[test]
+    fn lookup() {
+        let origin = Coordinate { x: 0, y: 0 };
+        let grid = label_grid(&[
+            (origin, "origin"),
+            (Coordinate { x: 1, y: 0 }, "east"),
+            (Coordinate { x: 0, y: 0 }, "home"),
+        ]);
+
+        // The second `(0, 0)` replaced the first, rather than adding a key.
+        assert_eq!(grid.len(), 2);
+        // A freshly built, equal coordinate finds the entry.
+        assert_eq!(grid[&Coordinate { x: 0, y: 0 }], "home");
+        assert_eq!(grid.get(&Coordinate { x: 0, y: 1 }), None);
+    }
+}
@@ -0,0 +1,54 @@
+//! Removing duplicates with `dedup` and `dedup_by_key`.
+//!
+//! The surprise with `Vec::dedup` is that it only removes *consecutive*
+//! duplicates: `[1, 2, 1]` stays as it is. That's what lets it work in a
+//! single pass, in place, without any extra memory---it only ever compares
+//! each element with the one before it. To remove every duplicate, sort
+//! first (if the order doesn't matter), or collect into a `HashSet` or
+//! `BTreeSet`.
+
+/// Collapse each run of equal elements into a single element.
+pub fn collapse_runs(data: &mut Vec<i32>) {
+    data.dedup();
+}
+
+/// Collapse each run of elements with equal absolute values into its first
+/// element.
+///
+/// `dedup_by_key` compares the keys the closure computes, rather than the
+/// elements themselves; when several elements in a row share a key, the first
+/// one is kept.
+pub fn dedup_by_abs(data: &mut Vec<i32>) {
+    data.dedup_by_key(|x| x.abs());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_collapse() {
+        let mut data = vec![1, 1, 2, 3, 3, 3, 4, 1, 1];
+        collapse_runs(&mut data);
+        // The trailing 1 isn't next to the leading one, so it stays.
+        assert_eq!(data, vec![1, 2, 3, 4, 1]);
+    }
+
+    #[test]
+    fn non_adjacent_duplicates_survive() {
+        let mut data = vec![3, 1, 3, 1];
+        collapse_runs(&mut data);
+        assert_eq!(data, vec![3, 1, 3, 1]);
+
+        data.sort_unstable();
+        collapse_runs(&mut data);
+        assert_eq!(data, vec![1, 3]);
+    }
+
+    #[test]
+    fn by_absolute_value() {
+        let mut data = vec![-2, 2, -2, 5, 3, -3, 2];
+        dedup_by_abs(&mut data);
+        assert_eq!(data, vec![-2, 5, 3, 2]);
+    }
+}
@@ -0,0 +1,55 @@
+//! Moving part of a vector out with `drain`.
+
+/// Remove the elements `start..end` from `data`, and return them.
+///
+/// If the range is backward or runs past the end of `data`, nothing is
+/// removed and the result is empty. (`drain` itself would panic.)
+///
+/// `drain` removes any range, shifting whatever came after it down to close
+/// the gap, and hands back the removed elements as an iterator---so we can
+/// collect them, as here, or process them one at a time without ever storing
+/// them. `data.split_off(at)` is the special case of draining `at..`: it's a
+/// little simpler, and returns a new `Vec` directly, but it can only split off
+/// the tail.
+pub fn extract_range(data: &mut Vec<i32>, start: usize, end: usize) -> Vec<i32> {
+    if start > end || end > data.len() {
+        return Vec::new();
+    }
+    data.drain(start..end).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn middle() {
+        let mut data = vec![1, 2, 3, 4, 5];
+        assert_eq!(extract_range(&mut data, 1, 3), vec![2, 3]);
+        assert_eq!(data, vec![1, 4, 5]);
+    }
+
+    #[test]
+    fn everything() {
+        let mut data = vec![1, 2, 3];
+        assert_eq!(extract_range(&mut data, 0, 3), vec![1, 2, 3]);
+        assert_eq!(data, vec![]);
+    }
+
+    #[test]
+    fn invalid_ranges() {
+        let mut data = vec![1, 2, 3];
+        assert_eq!(extract_range(&mut data, 2, 4), vec![]);
+        assert_eq!(extract_range(&mut data, 5, 6), vec![]);
+        assert_eq!(extract_range(&mut data, 2, 1), vec![]);
+        assert_eq!(data, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn tail_matches_split_off() {
+        let mut drained = vec![1, 2, 3, 4];
+        let mut split = drained.clone();
+        assert_eq!(extract_range(&mut drained, 2, 4), split.split_off(2));
+        assert_eq!(drained, split);
+    }
+}
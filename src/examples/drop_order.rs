@@ -0,0 +1,84 @@
+//! When do destructors run? Demonstrating `Drop` order.
+//!
+//! Local variables are dropped in the *reverse* of the order they were
+//! declared: last in, first out. That way, anything declared later (which
+//! might hold a reference to something declared earlier) is cleaned up first.
+
+use std::cell::RefCell;
+use std::mem;
+use std::rc::Rc;
+
+/// A shared record of which resources have been dropped, in order.
+pub type DropLog = Rc<RefCell<Vec<String>>>;
+
+/// A resource which records its own destruction.
+pub struct Resource {
+    name: String,
+    log: DropLog,
+}
+
+impl Resource {
+    pub fn new(name: &str, log: &DropLog) -> Resource {
+        Resource {
+            name: name.to_string(),
+            log: Rc::clone(log),
+        }
+    }
+}
+
+impl Drop for Resource {
+    fn drop(&mut self) {
+        self.log.borrow_mut().push(self.name.clone());
+    }
+}
+
+/// Create some resources in a scope, and report the order they were dropped.
+pub fn demonstrate_drop_order() -> Vec<String> {
+    let log = DropLog::default();
+    {
+        let _first = Resource::new("first", &log);
+        let _second = Resource::new("second", &log);
+        let _third = Resource::new("third", &log);
+    }
+
+    let order = log.borrow().clone();
+    order
+}
+
+/// Drop one resource early with `std::mem::drop`.
+///
+/// There's no magic in `mem::drop`: it's just `fn drop<T>(_x: T) {}`. Taking
+/// the argument by value moves it into the function, and then it goes out of
+/// scope at the end of that (empty) function body. (Note that you can't call
+/// `Drop::drop` yourself; the compiler won't let you, since it would then run
+/// a second time at the end of the scope.)
+pub fn demonstrate_early_drop() -> Vec<String> {
+    let log = DropLog::default();
+    {
+        let first = Resource::new("first", &log);
+        let _second = Resource::new("second", &log);
+        mem::drop(first);
+        log.borrow_mut().push(String::from("(end of scope)"));
+    }
+
+    let order = log.borrow().clone();
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drops_last_in_first_out() {
+        assert_eq!(demonstrate_drop_order(), vec!["third", "second", "first"]);
+    }
+
+    #[test]
+    fn early_drop() {
+        assert_eq!(
+            demonstrate_early_drop(),
+            vec!["first", "(end of scope)", "second"]
+        );
+    }
+}
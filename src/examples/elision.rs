@@ -0,0 +1,123 @@
+//! Lifetime elision: when we can leave lifetimes out, and when we can't.
+//!
+//! Every reference has a lifetime, but most function signatures never mention
+//! them, because the compiler fills them in using three *elision rules*:
+//!
+//!  1. Each reference parameter gets its own lifetime.
+//!  2. If there is exactly one input lifetime, it's given to every reference
+//!     in the output.
+//!  3. If one of the parameters is `&self` or `&mut self`, *its* lifetime is
+//!     given to every reference in the output.
+//!
+//! If the rules leave any output lifetime undetermined, elision fails and we
+//! have to write the annotations ourselves. See [e013] for more on lifetimes,
+//! and the [`lifetimes`] example for the classic `longest` function.
+//!
+//! [e013]: /show_notes/e013/index.html
+//! [`lifetimes`]: ../lifetimes/index.html
+
+/// The first space-separated word of `s`.
+///
+/// Here elision works: rule 1 gives `s` a lifetime, and since it's the only
+/// one, rule 2 gives it to the return type too. The signature the compiler
+/// actually sees is `fn first_word<'a>(s: &'a str) -> &'a str`.
+pub fn first_word(s: &str) -> &str {
+    s.split(' ').next().unwrap_or("")
+}
+
+/// Whichever of `text` and `fallback` comes first alphabetically.
+///
+/// With two reference parameters, rule 1 gives them two *different*
+/// lifetimes, and rule 2 doesn't apply, so the compiler can't tell which one
+/// the output borrows from. We have to say: `'a` on all three means the result
+/// is valid only for as long as *both* inputs are.
+///
+/// Leaving the annotations off is an error:
+///
+/// ```compile_fail
+/// fn earlier(text: &str, fallback: &str) -> &str { // error[E0106]: missing lifetime specifier
+///     if text <= fallback { text } else { fallback }
+/// }
+/// ```
+///
+/// An annotation doesn't have to mention every parameter, though. If the
+/// result could only ever come from the first one, we could write
+/// `fn f<'a>(x: &'a str, y: &str) -> &'a str`, leaving `y` free to have a
+/// shorter lifetime.
+pub fn earlier<'a>(text: &'a str, fallback: &'a str) -> &'a str {
+    if text <= fallback {
+        text
+    } else {
+        fallback
+    }
+}
+
+/// A parser holding on to the text it hasn't consumed yet.
+pub struct Words<'t> {
+    rest: &'t str,
+}
+
+impl<'t> Words<'t> {
+    /// Start parsing `text`.
+    pub fn new(text: &'t str) -> Words<'t> {
+        Words { rest: text }
+    }
+
+    /// The text which hasn't been consumed yet.
+    ///
+    /// Rule 3 at work: the output gets the lifetime of `&self`, even though
+    /// there's no other input here which it could be confused with.
+    pub fn remaining(&self) -> &str {
+        self.rest
+    }
+
+    /// Consume and return the next word, if there is one.
+    ///
+    /// Here rule 3 would tie the word to `&mut self`, which would mean we
+    /// couldn't call `next_word` again while still holding on to the previous
+    /// word. But the word really borrows from the original text, so we say so
+    /// with `'t`, overriding elision.
+    pub fn next_word(&mut self, separator: &str) -> Option<&'t str> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (word, rest) = match self.rest.find(separator) {
+            Some(index) => (&self.rest[..index], &self.rest[index + separator.len()..]),
+            None => (self.rest, ""),
+        };
+        self.rest = rest;
+        Some(word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_word_of_sentence() {
+        assert_eq!(first_word("hello world"), "hello");
+        assert_eq!(first_word("hello"), "hello");
+        assert_eq!(first_word(""), "");
+    }
+
+    #[test]
+    fn earlier_picks_alphabetically() {
+        assert_eq!(earlier("apple", "banana"), "apple");
+        assert_eq!(earlier("cherry", "banana"), "banana");
+    }
+
+    #[test]
+    fn words_outlive_the_borrow_of_the_parser() {
+        let text = String::from("one, two, three");
+        let mut words = Words::new(&text);
+        let first = words.next_word(", ").unwrap();
+        let second = words.next_word(", ").unwrap();
+        // Both words are still usable here, because they borrow from `text`,
+        // not from `words`.
+        assert_eq!((first, second), ("one", "two"));
+        assert_eq!(words.remaining(), "three");
+        assert_eq!(words.next_word(", "), Some("three"));
+        assert_eq!(words.next_word(", "), None);
+    }
+}
@@ -0,0 +1,49 @@
+//! Counting with the `HashMap` entry API.
+//!
+//! The obvious way to add to a per-key total is to check whether the key is
+//! there, insert a zero if it isn't, and then look it up *again* to update it.
+//! That's two or three hash lookups where one will do, and it's awkward to
+//! write, since the borrow checker won't let us hold on to a reference from the
+//! first lookup while we insert.
+
+use std::collections::HashMap;
+
+/// Sum the values for each key.
+///
+/// `entry` does the lookup once, and hands back an `Entry`: either `Occupied`,
+/// with the existing value, or `Vacant`, with the spot where a value would go.
+/// `or_insert(0)` fills in a vacant entry, and either way returns a `&mut i32`
+/// to the value, which we can add to directly. No second lookup, and no
+/// possibility of the check and the insert getting out of sync.
+pub fn accumulate(pairs: &[(&str, i32)]) -> HashMap<String, i32> {
+    let mut totals = HashMap::new();
+    for &(key, value) in pairs {
+        *totals.entry(key.to_string()).or_insert(0) += value;
+    }
+    totals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_keys() {
+        let totals = accumulate(&[("apples", 3), ("pears", 2), ("apples", -1), ("apples", 5)]);
+        assert_eq!(totals.len(), 2);
+        assert_eq!(totals["apples"], 7);
+        assert_eq!(totals["pears"], 2);
+    }
+
+    #[test]
+    fn single_key() {
+        let totals = accumulate(&[("only", 42)]);
+        assert_eq!(totals.len(), 1);
+        assert_eq!(totals["only"], 42);
+    }
+
+    #[test]
+    fn empty() {
+        assert!(accumulate(&[]).is_empty());
+    }
+}
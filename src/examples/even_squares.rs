@@ -0,0 +1,46 @@
+//! Lazy iterator chains.
+//!
+//! Iterator adapters like `filter`, `map`, and `take` don't do any work when
+//! they're called: each one just wraps the iterator before it in a new struct.
+//! Nothing happens until something *consumes* the chain---`collect`, `sum`, a
+//! `for` loop---and then each element is pulled through every stage, one at a
+//! time, only as it's needed.
+
+/// The squares of the first `n` even numbers, starting from 2.
+///
+/// `1u64..` is an infinite range, and collecting it directly would never
+/// finish. But thanks to laziness, it's perfectly usable here: `take(n)` stops
+/// asking for elements once it has `n` of them, so the range only ever gets as
+/// far as `2 * n`. (The flip side: leave off the `take`, and the `collect`
+/// really would run forever---or at least until the multiplication
+/// overflowed.)
+pub fn first_n_even_squares(n: usize) -> Vec<u64> {
+    (1u64..)
+        .filter(|x| x % 2 == 0)
+        .map(|x| x * x)
+        .take(n)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn none() {
+        assert_eq!(first_n_even_squares(0), Vec::<u64>::new());
+    }
+
+    #[test]
+    fn three() {
+        assert_eq!(first_n_even_squares(3), vec![4, 16, 36]);
+    }
+
+    #[test]
+    fn many() {
+        let squares = first_n_even_squares(1000);
+        assert_eq!(squares.len(), 1000);
+        assert_eq!(squares[999], 2000 * 2000);
+        assert!(squares.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
@@ -0,0 +1,58 @@
+//! A recursive `enum`: evaluating arithmetic expressions.
+//!
+//! An `Expr` can contain other `Expr`s, but it can't contain them *directly*:
+//! the compiler needs to know how big every type is, and a type which contains
+//! itself would be infinitely large. Putting the inner expressions in a `Box`
+//! solves that, since a `Box` is always just one pointer in size no matter
+//! what it points to.
+
+/// An arithmetic expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Num(f64),
+    Add(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+}
+
+/// Evaluate an expression.
+///
+/// The shape of the function follows the shape of the type: one `match` arm
+/// per variant, recursing wherever the type does.
+pub fn eval(e: &Expr) -> f64 {
+    match e {
+        Expr::Num(n) => *n,
+        Expr::Add(lhs, rhs) => eval(lhs) + eval(rhs),
+        Expr::Mul(lhs, rhs) => eval(lhs) * eval(rhs),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn num(n: f64) -> Box<Expr> {
+        Box::new(Expr::Num(n))
+    }
+
+    #[test]
+    fn single_number() {
+        assert_eq!(eval(&Expr::Num(42.0)), 42.0);
+    }
+
+    #[test]
+    fn small_tree() {
+        // (2 + 3) * 4
+        let e = Expr::Mul(Box::new(Expr::Add(num(2.0), num(3.0))), num(4.0));
+        assert_eq!(eval(&e), 20.0);
+    }
+
+    #[test]
+    fn deeply_nested() {
+        // 1 + (1 + (1 + ... ))
+        let mut e = Expr::Num(1.0);
+        for _ in 0..1000 {
+            e = Expr::Add(num(1.0), Box::new(e));
+        }
+        assert_eq!(eval(&e), 1001.0);
+    }
+}
@@ -0,0 +1,69 @@
+//! Calling C from Rust: a binding to the C standard library's `abs`.
+//!
+//! An `extern "C"` block *declares* functions which are defined somewhere
+//! else, using the C calling convention. We don't need to tell Cargo to link
+//! anything here, since the Rust standard library already links against the
+//! platform's C library.
+
+use std::os::raw::c_int;
+
+extern "C" {
+    /// `int abs(int)`, from `<stdlib.h>`.
+    ///
+    /// Calling any foreign function is `unsafe`: the compiler can't check what
+    /// the C code does, so it's on us to make sure each call is sound.
+    fn abs(input: c_int) -> c_int;
+}
+
+/// Get the absolute value of `x`, using C's `abs`.
+///
+/// C's `int` doesn't have a fixed size: it's 32 bits on the usual desktop and
+/// server platforms, but only 16 on some microcontrollers, like AVR and
+/// MSP430. Writing `i32` in the declaration above would be a promise the
+/// compiler can't check, and on those targets, a wrong one. `c_int` is an
+/// alias for whichever Rust integer type matches the target's C `int`, so
+/// using it (here and in the declaration) guarantees we get the C ABI right
+/// everywhere.
+///
+/// There's one input which needs special care: `c_int::MIN`. In two's
+/// complement, there's one more negative number than positive number, so the
+/// absolute value of the minimum doesn't fit. In C, `abs(INT_MIN)` is
+/// *undefined behavior*---so this wrapper can't just pass it along and still
+/// be safe. Instead, we handle it ourselves, matching Rust's own
+/// `wrapping_abs`: the result wraps back around to `c_int::MIN`.
+pub fn c_abs(x: c_int) -> c_int {
+    if x == c_int::MIN {
+        return c_int::MIN;
+    }
+
+    // This is sound: the declaration uses the platform's real `int` type, and
+    // we've ruled out the only input for which `abs` is undefined.
+    unsafe { abs(x) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positive() {
+        assert_eq!(c_abs(42), 42);
+        assert_eq!(c_abs(c_int::MAX), c_int::MAX);
+    }
+
+    #[test]
+    fn negative() {
+        assert_eq!(c_abs(-42), 42);
+        assert_eq!(c_abs(-c_int::MAX), c_int::MAX);
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(c_abs(0), 0);
+    }
+
+    #[test]
+    fn minimum() {
+        assert_eq!(c_abs(c_int::MIN), c_int::MIN.wrapping_abs());
+    }
+}
@@ -0,0 +1,72 @@
+//! Benchmarking recursion against iteration.
+//!
+//! [e007] benchmarks `add`, which is about as fast as code gets. Two ways of
+//! computing Fibonacci numbers make for a more interesting comparison. Run
+//! `cargo bench fib` (on nightly) to see just how big the difference is.
+//!
+//! [e007]: /show_notes/e007/index.html
+
+/// The `n`th Fibonacci number, by naive recursion.
+///
+/// This is a direct transcription of the definition, and it's exponentially
+/// slow. Each call makes two more, and those calls recompute the same values
+/// over and over: `fib_recursive(n - 1)` computes `fib_recursive(n - 2)` all
+/// over again, and so on all the way down. The number of calls grows like the
+/// Fibonacci numbers themselves, roughly 1.6ⁿ: about 22,000 calls for
+/// `n = 20`, and over 300 *million* for `n = 40`.
+pub fn fib_recursive(n: u32) -> u64 {
+    match n {
+        0 => 0,
+        1 => 1,
+        _ => fib_recursive(n - 1) + fib_recursive(n - 2),
+    }
+}
+
+/// The `n`th Fibonacci number, by iteration.
+///
+/// Keeping just the last two values around makes this O(n), with no
+/// recursion at all.
+pub fn fib_iterative(n: u32) -> u64 {
+    let (mut current, mut next) = (0_u64, 1_u64);
+    for _ in 0..n {
+        let new_next = current + next;
+        current = next;
+        next = new_next;
+    }
+    current
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::{black_box, Bencher};
+
+    #[test]
+    fn known_values() {
+        assert_eq!(fib_iterative(0), 0);
+        assert_eq!(fib_iterative(1), 1);
+        assert_eq!(fib_iterative(10), 55);
+        assert_eq!(fib_iterative(20), 6765);
+    }
+
+    #[test]
+    fn implementations_agree() {
+        for n in 0..=20 {
+            assert_eq!(fib_recursive(n), fib_iterative(n), "n = {}", n);
+        }
+    }
+
+    // `black_box` stops the optimizer from noticing that the argument is a
+    // constant and computing the answer at compile time, which would leave us
+    // benchmarking nothing at all.
+
+    #[bench]
+    fn bench_fib_recursive(bencher: &mut Bencher) {
+        bencher.iter(|| fib_recursive(black_box(20)));
+    }
+
+    #[bench]
+    fn bench_fib_iterative(bencher: &mut Bencher) {
+        bencher.iter(|| fib_iterative(black_box(20)));
+    }
+}
@@ -0,0 +1,65 @@
+//! A custom `Iterator`: the Fibonacci sequence.
+
+/// An iterator over the Fibonacci numbers which fit in a `u64`.
+pub struct Fibonacci {
+    curr: Option<u64>,
+    next: Option<u64>,
+}
+
+impl Fibonacci {
+    pub fn new() -> Fibonacci {
+        Fibonacci {
+            curr: Some(0),
+            next: Some(1),
+        }
+    }
+}
+
+impl Default for Fibonacci {
+    fn default() -> Fibonacci {
+        Fibonacci::new()
+    }
+}
+
+/// Implementing `Iterator` just means defining `Item` and `next`; we get all
+/// the other iterator methods (`take`, `map`, `sum`, ...) for free.
+impl Iterator for Fibonacci {
+    type Item = u64;
+
+    /// Get the next Fibonacci number, or `None` once we'd overflow.
+    ///
+    /// A plain `curr + next` would panic in debug builds (and wrap in release
+    /// builds!) once the numbers get too big. `checked_add` gives us an
+    /// `Option` instead, and a `None` there means the number *after* `next`
+    /// doesn't fit---but `curr` and `next` themselves still do, and still have
+    /// to be yielded. So we store the `None` and let it move along to `curr`,
+    /// where `?` turns it into the end of the iteration. From then on, `curr`
+    /// stays `None`, so every later call returns `None` too.
+    fn next(&mut self) -> Option<u64> {
+        let current = self.curr?;
+        let new_next = self.next.and_then(|next| next.checked_add(current));
+        self.curr = self.next;
+        self.next = new_next;
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_ten() {
+        let first_ten: Vec<u64> = Fibonacci::new().take(10).collect();
+        assert_eq!(first_ten, vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+    }
+
+    #[test]
+    fn stops_at_overflow() {
+        let mut fib = Fibonacci::new();
+        let last = fib.by_ref().last();
+        assert_eq!(last, Some(12_200_160_415_121_876_738));
+        assert_eq!(fib.next(), None);
+        assert_eq!(Fibonacci::new().count(), 94);
+    }
+}
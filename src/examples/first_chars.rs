@@ -0,0 +1,61 @@
+//! Slicing strings by characters rather than bytes.
+//!
+//! As [e014] covers, a `String` or `&str` is UTF-8, so a single `char` can be
+//! anywhere from one to four bytes long. String indices are *byte* offsets, and
+//! slicing at an offset which falls in the middle of a character panics: in
+//! `"héllo"`, the `é` is two bytes, so `&"héllo"[..2]` panics with "byte index
+//! 2 is not a char boundary". To slice by characters, we first need to find
+//! out where in the bytes each character starts.
+//!
+//! [e014]: /show_notes/e014/index.html
+
+/// The first `n` characters (Unicode scalar values) of `s`.
+///
+/// If `s` has `n` or fewer characters, that's all of `s`.
+///
+/// `char_indices` yields each character along with the byte offset where it
+/// starts. The `n`th one's offset (counting from zero) is exactly where the
+/// first `n` characters end, so it's always a safe place to slice.
+pub fn first_n_chars(s: &str, n: usize) -> &str {
+    match s.char_indices().nth(n) {
+        Some((end, _)) => &s[..end],
+        None => s,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii() {
+        assert_eq!(first_n_chars("hello", 0), "");
+        assert_eq!(first_n_chars("hello", 3), "hel");
+        assert_eq!(first_n_chars("hello", 5), "hello");
+    }
+
+    #[test]
+    fn multi_byte() {
+        assert_eq!(first_n_chars("héllo", 2), "hé");
+        assert_eq!(first_n_chars("héllo", 2).len(), 3);
+    }
+
+    #[test]
+    fn emoji() {
+        assert_eq!(first_n_chars("🦀🦀rust", 1), "🦀");
+        assert_eq!(first_n_chars("🦀🦀rust", 3), "🦀🦀r");
+    }
+
+    #[test]
+    fn n_past_the_end() {
+        assert_eq!(first_n_chars("héllo", 10), "héllo");
+        assert_eq!(first_n_chars("", 1), "");
+    }
+
+    #[test]
+    #[should_panic(expected = "char boundary")]
+    fn byte_slicing_panics() {
+        let s = String::from("héllo");
+        let _ = &s[..2];
+    }
+}
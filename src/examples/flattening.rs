@@ -0,0 +1,69 @@
+//! Nested iteration with `flat_map` and `flatten`.
+//!
+//! `flatten` takes an iterator whose items are themselves iterable---an
+//! iterator of `Vec`s, say, or of `Option`s---and yields all of *their* items
+//! in turn, removing one level of nesting. `flat_map(f)` is exactly
+//! `map(f).flatten()`: use it when you're producing the inner iterators
+//! yourself, and `flatten` when you already have them.
+
+/// Every pair of one element from `xs` and one from `ys` (their Cartesian
+/// product), in order.
+///
+/// For each `x`, the closure returns an iterator over all the pairs starting
+/// with that `x`, and `flat_map` strings those together. The inner closure
+/// needs `move` so that it takes its own copy of `x`, rather than borrowing one
+/// which only lives for a single call of the outer closure.
+pub fn all_pairs(xs: &[i32], ys: &[i32]) -> Vec<(i32, i32)> {
+    xs.iter()
+        .flat_map(|&x| ys.iter().map(move |&y| (x, y)))
+        .collect()
+}
+
+/// All the whitespace-separated words in all of `sentences`.
+///
+/// Here the `map` gives us an iterator of iterators (one `SplitWhitespace` per
+/// sentence), and `flatten` joins them into a single iterator of words.
+/// (Clippy would rather we used `flat_map` here; but this is the `flatten`
+/// example.)
+#[allow(clippy::map_flatten)]
+pub fn concat_words(sentences: &[&str]) -> Vec<String> {
+    sentences
+        .iter()
+        .map(|sentence| sentence.split_whitespace())
+        .flatten()
+        .map(String::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pairs() {
+        assert_eq!(
+            all_pairs(&[1, 2], &[3, 4, 5]),
+            vec![(1, 3), (1, 4), (1, 5), (2, 3), (2, 4), (2, 5)]
+        );
+    }
+
+    #[test]
+    fn empty_product() {
+        assert_eq!(all_pairs(&[], &[1, 2]), vec![]);
+        assert_eq!(all_pairs(&[1, 2], &[]), vec![]);
+    }
+
+    #[test]
+    fn words() {
+        assert_eq!(
+            concat_words(&["the quick", "  brown fox ", "jumps"]),
+            vec!["the", "quick", "brown", "fox", "jumps"]
+        );
+    }
+
+    #[test]
+    fn no_words() {
+        assert_eq!(concat_words(&[]), Vec::<String>::new());
+        assert_eq!(concat_words(&["", "   "]), Vec::<String>::new());
+    }
+}
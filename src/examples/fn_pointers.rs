@@ -0,0 +1,65 @@
+//! Function pointers (`fn`) versus closure traits (`Fn`).
+//!
+//! Lowercase `fn(i32) -> i32` is a *type*: a plain pointer to some function's
+//! code. Uppercase `Fn(i32) -> i32` is a *trait*, implemented by closures---and
+//! by functions and function pointers, too.
+
+/// Call `f` with `x`, where `f` is a function pointer.
+///
+/// A `fn` pointer is one concrete type, so `apply` is compiled just once, and
+/// `f` can be stored or passed around like any other value. It can point at any
+/// named function with the right signature, and at closures which don't
+/// capture anything (they're coerced automatically). What it can't hold is a
+/// closure which *does* capture its environment, since a bare code pointer has
+/// nowhere to keep the captured values. So a `fn` pointer is enough for things
+/// like lookup tables of operations. (Callbacks for C code need a slightly
+/// different type, `extern "C" fn(i32) -> i32`: the `extern "C"` is part of
+/// the type, since C expects its own calling convention, and a plain Rust
+/// `fn` doesn't use it.)
+pub fn apply(f: fn(i32) -> i32, x: i32) -> i32 {
+    f(x)
+}
+
+/// Call `f` with `x`, where `f` is anything which implements `Fn`.
+///
+/// Being generic, this accepts capturing closures too, and each call site
+/// gets its own copy of `apply_closure`, specialized to that `F`---which
+/// usually means the call can be inlined. It's the more flexible choice for
+/// taking a callback, and the usual one.
+pub fn apply_closure<F: Fn(i32) -> i32>(f: F, x: i32) -> i32 {
+    f(x)
+}
+
+/// Double `x`. A named function, for passing to the other two.
+pub fn double(x: i32) -> i32 {
+    x * 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn function_pointer() {
+        assert_eq!(apply(double, 21), 42);
+        // A closure which captures nothing becomes a `fn` pointer.
+        assert_eq!(apply(|x| x - 1, 21), 20);
+    }
+
+    #[test]
+    fn generic() {
+        assert_eq!(apply_closure(double, 21), 42);
+        let offset = 100;
+        assert_eq!(apply_closure(|x| x + offset, 21), 121);
+    }
+
+    #[test]
+    fn table_of_operations() {
+        fn negate(x: i32) -> i32 {
+            -x
+        }
+        let operations: [fn(i32) -> i32; 2] = [double, negate];
+        let results: Vec<i32> = operations.iter().map(|&op| apply(op, 5)).collect();
+        assert_eq!(results, vec![10, -5]);
+    }
+}
@@ -0,0 +1,87 @@
+//! Default methods on traits.
+//!
+//! A trait can provide a body for some of its methods. Implementors then get
+//! that method for free, but can still override it with their own. And since a
+//! default body is written against the trait itself, it can call any of the
+//! trait's other methods---including *required* ones like `name` here, which
+//! each implementor must supply. That's a common pattern: one small required
+//! method, and a raft of conveniences built on top of it. (`Iterator` is the
+//! extreme case: implement `next`, and you get dozens of methods, from `map`
+//! to `sum`.) See [e008] for more on traits.
+//!
+//! [e008]: /show_notes/e008/index.html
+
+/// Something which can say hello.
+pub trait Greet {
+    /// Who is doing the greeting.
+    fn name(&self) -> String;
+
+    /// A greeting, which by default just uses `name`.
+    fn greeting(&self) -> String {
+        format!("Hello, {}!", self.name())
+    }
+}
+
+/// A guest, who is happy with the default greeting.
+pub struct Guest {
+    pub name: String,
+}
+
+impl Greet for Guest {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+/// A pirate, who is not.
+pub struct Pirate {
+    pub name: String,
+}
+
+impl Greet for Pirate {
+    fn name(&self) -> String {
+        format!("Captain {}", self.name)
+    }
+
+    fn greeting(&self) -> String {
+        format!("Ahoy! {} at yer service.", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_greeting() {
+        let guest = Guest {
+            name: String::from("Chris"),
+        };
+        assert_eq!(guest.greeting(), "Hello, Chris!");
+    }
+
+    #[test]
+    fn overridden_greeting() {
+        let pirate = Pirate {
+            name: String::from("Ferris"),
+        };
+        assert_eq!(pirate.greeting(), "Ahoy! Captain Ferris at yer service.");
+    }
+
+    #[test]
+    fn trait_objects_pick_the_right_greeting() {
+        let greeters: Vec<Box<dyn Greet>> = vec![
+            Box::new(Guest {
+                name: String::from("Chris"),
+            }),
+            Box::new(Pirate {
+                name: String::from("Ferris"),
+            }),
+        ];
+        let greetings: Vec<String> = greeters.iter().map(|g| g.greeting()).collect();
+        assert_eq!(
+            greetings,
+            vec!["Hello, Chris!", "Ahoy! Captain Ferris at yer service."]
+        );
+    }
+}
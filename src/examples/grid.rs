@@ -0,0 +1,90 @@
+//! Implementing the indexing operators, `Index` and `IndexMut`.
+
+use std::ops::{Index, IndexMut};
+
+/// A fixed-size, two-dimensional grid of bytes.
+///
+/// The cells are stored in one flat `Vec`, in *row-major* order: all of row
+/// 0, then all of row 1, and so on. So the cell at column `x`, row `y` lives
+/// at index `y * width + x`. For a grid 3 wide, `(2, 1)` is at `1 * 3 + 2 = 5`:
+///
+/// ```text
+///        x=0 x=1 x=2
+///  y=0 [  0,  1,  2,
+///  y=1    3,  4,  5,
+///  y=2    6,  7,  8 ]
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid {
+    width: usize,
+    height: usize,
+    cells: Vec<u8>,
+}
+
+impl Grid {
+    /// Create a grid with every cell set to zero.
+    pub fn new(width: usize, height: usize) -> Grid {
+        Grid {
+            width,
+            height,
+            cells: vec![0; width * height],
+        }
+    }
+
+    /// Convert `(x, y)` coordinates into an index into `cells`.
+    ///
+    /// We have to check `x` ourselves: an `x` past the end of a row would
+    /// otherwise silently wrap around into the next row.
+    fn offset(&self, (x, y): (usize, usize)) -> usize {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is out of bounds for a {}x{} grid",
+            x,
+            y,
+            self.width,
+            self.height
+        );
+        y * self.width + x
+    }
+}
+
+impl Index<(usize, usize)> for Grid {
+    type Output = u8;
+
+    fn index(&self, coordinates: (usize, usize)) -> &u8 {
+        &self.cells[self.offset(coordinates)]
+    }
+}
+
+/// `IndexMut` builds on `Index`: it reuses `Index::Output` as its type, and
+/// is what makes `grid[(x, y)] = value` work.
+impl IndexMut<(usize, usize)> for Grid {
+    fn index_mut(&mut self, coordinates: (usize, usize)) -> &mut u8 {
+        let offset = self.offset(coordinates);
+        &mut self.cells[offset]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_and_write() {
+        let mut grid = Grid::new(3, 2);
+        assert_eq!(grid[(2, 1)], 0);
+
+        grid[(2, 1)] = 7;
+        grid[(0, 1)] += 1;
+        assert_eq!(grid[(2, 1)], 7);
+        assert_eq!(grid[(0, 1)], 1);
+        assert_eq!(grid.cells, vec![0, 0, 0, 1, 0, 7]);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn out_of_bounds() {
+        let grid = Grid::new(3, 2);
+        let _ = grid[(3, 0)];
+    }
+}
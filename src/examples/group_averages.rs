@@ -0,0 +1,54 @@
+//! Fixed-size groups with `chunks_exact`.
+
+/// The average of each complete group of `group` elements of `data`, and
+/// whatever was left over at the end.
+///
+/// Panics if `group` is 0.
+///
+/// `chunks` would also split `data` into groups, but its last chunk can be
+/// shorter than the rest, so the code consuming it has to allow for any
+/// length. `chunks_exact` only yields full-sized chunks, and sets any ragged
+/// tail aside, to be fetched with `remainder`. Apart from making the two
+/// cases explicit, that helps the optimizer: inside the loop, every chunk is
+/// known to be exactly `group` long, so bounds checks can go away and the
+/// loop body is easier to unroll or vectorize.
+pub fn average_in_groups(data: &[f64], group: usize) -> (Vec<f64>, Vec<f64>) {
+    let chunks = data.chunks_exact(group);
+    let remainder = chunks.remainder().to_vec();
+    let averages = chunks
+        .map(|chunk| chunk.iter().sum::<f64>() / group as f64)
+        .collect();
+    (averages, remainder)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_multiple() {
+        let (averages, remainder) = average_in_groups(&[1.0, 3.0, 2.0, 2.0, 10.0, 0.0], 2);
+        assert_eq!(averages, vec![2.0, 2.0, 5.0]);
+        assert_eq!(remainder, vec![]);
+    }
+
+    #[test]
+    fn ragged_tail() {
+        let (averages, remainder) = average_in_groups(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0], 3);
+        assert_eq!(averages, vec![2.0, 5.0]);
+        assert_eq!(remainder, vec![7.0, 8.0]);
+    }
+
+    #[test]
+    fn group_longer_than_data() {
+        let (averages, remainder) = average_in_groups(&[1.0, 2.0], 5);
+        assert_eq!(averages, vec![]);
+        assert_eq!(remainder, vec![1.0, 2.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_group() {
+        average_in_groups(&[1.0], 0);
+    }
+}
@@ -0,0 +1,61 @@
+//! Consuming part of an iterator with `by_ref`.
+//!
+//! Most adapters, like `take_while`, take the iterator they wrap *by value*,
+//! so once we've called one, the original is gone. `by_ref` gets around that:
+//! it returns a `&mut` to the iterator, and `&mut I` is itself an iterator
+//! (which just calls `next` on the `I`). The adapter consumes the borrow
+//! instead, and when it's done, we still have the original iterator, picking
+//! up wherever the adapter left off.
+
+/// Split `lines` into a header, which runs up to the first blank line, and a
+/// body, which is everything after it.
+///
+/// The blank line itself is in neither. Without one, everything is header.
+pub fn take_header_then_body(lines: &[&str]) -> (Vec<String>, Vec<String>) {
+    let mut lines = lines.iter();
+
+    // `take_while` has to pull the blank line out of the iterator to find out
+    // that it's where the header stops. It doesn't yield it, though, so this
+    // conveniently skips the separator altogether.
+    let header = lines
+        .by_ref()
+        .take_while(|line| !line.trim().is_empty())
+        .map(|line| line.to_string())
+        .collect();
+    let body = lines.map(|line| line.to_string()).collect();
+    (header, body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_separator() {
+        let (header, body) = take_header_then_body(&[
+            "From: ferris@example.com",
+            "Subject: Hi",
+            "",
+            "Hello!",
+            "",
+            "Bye.",
+        ]);
+        assert_eq!(header, vec!["From: ferris@example.com", "Subject: Hi"]);
+        // Only the first blank line separates; later ones are part of the body.
+        assert_eq!(body, vec!["Hello!", "", "Bye."]);
+    }
+
+    #[test]
+    fn without_separator() {
+        let (header, body) = take_header_then_body(&["one", "two"]);
+        assert_eq!(header, vec!["one", "two"]);
+        assert_eq!(body, Vec::<String>::new());
+    }
+
+    #[test]
+    fn leading_separator() {
+        let (header, body) = take_header_then_body(&["   ", "body"]);
+        assert_eq!(header, Vec::<String>::new());
+        assert_eq!(body, vec!["body"]);
+    }
+}
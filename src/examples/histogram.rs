@@ -0,0 +1,63 @@
+//! Collecting into a custom type with `FromIterator`.
+//!
+//! `collect` is generic over its *return* type: its signature is
+//! `fn collect<B: FromIterator<Self::Item>>(self) -> B`. That's why you so
+//! often have to tell it what you want, with `collect::<Vec<_>>()` or a type
+//! annotation on the binding---the compiler picks the `FromIterator`
+//! implementation based on the type you ask for, and then `collect` just calls
+//! `B::from_iter(self)`. Implement `FromIterator` for your own type, and it
+//! becomes one more thing `collect` can build.
+
+use std::collections::HashMap;
+use std::iter::FromIterator;
+
+/// How many times each character appears.
+#[derive(Debug, Default, PartialEq)]
+pub struct Histogram {
+    pub counts: HashMap<char, usize>,
+}
+
+impl Histogram {
+    /// How many times `c` was seen.
+    pub fn count(&self, c: char) -> usize {
+        self.counts.get(&c).copied().unwrap_or(0)
+    }
+}
+
+impl FromIterator<char> for Histogram {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Histogram {
+        let mut histogram = Histogram::default();
+        for c in iter {
+            *histogram.counts.entry(c).or_insert(0) += 1;
+        }
+        histogram
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let histogram: Histogram = "".chars().collect();
+        assert!(histogram.counts.is_empty());
+        assert_eq!(histogram.count('a'), 0);
+    }
+
+    #[test]
+    fn repeated_characters() {
+        let histogram = "hello".chars().collect::<Histogram>();
+        assert_eq!(histogram.count('l'), 2);
+        assert_eq!(histogram.count('h'), 1);
+        assert_eq!(histogram.count('o'), 1);
+        assert_eq!(histogram.count('z'), 0);
+        assert_eq!(histogram.counts.len(), 4);
+    }
+
+    #[test]
+    fn from_any_char_iterator() {
+        let histogram: Histogram = vec!['a', 'b', 'a'].into_iter().collect();
+        assert_eq!(histogram.count('a'), 2);
+    }
+}
@@ -0,0 +1,75 @@
+//! Zero-copy parsing with string slices.
+
+/// Parse lines of `key = value` into `(key, value)` pairs.
+///
+/// Blank lines, lines with no `=`, and lines with nothing before the `=` (like
+/// `=value`) are skipped. Whitespace around keys
+/// and values is trimmed, and a value may itself contain `=`: only the first
+/// one on a line splits it.
+///
+/// Nothing here allocates a new string. `lines`, `split_once`, and `trim` all
+/// return slices which point back into `input`, so the returned pairs are
+/// just pointer-and-length views of the original text. That's what the
+/// (elided) lifetimes in the signature say: written out in full, it's
+/// `fn parse_key_values<'a>(input: &'a str) -> Vec<(&'a str, &'a str)>`. The
+/// pairs are only valid as long as `input` is, and the compiler will stop you
+/// from keeping them around any longer.
+pub fn parse_key_values(input: &str) -> Vec<(&str, &str)> {
+    input
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| (key.trim(), value.trim()))
+        .filter(|(key, _)| !key.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lf_line_endings() {
+        assert_eq!(
+            parse_key_values("name=ferris\nlegs=10\n"),
+            vec![("name", "ferris"), ("legs", "10")]
+        );
+    }
+
+    #[test]
+    fn crlf_line_endings() {
+        // `lines` strips the `\r` from `\r\n` for us.
+        assert_eq!(
+            parse_key_values("name=ferris\r\nlegs=10\r\n"),
+            vec![("name", "ferris"), ("legs", "10")]
+        );
+    }
+
+    #[test]
+    fn whitespace_blank_lines_and_junk() {
+        let input = "  name =  ferris  \n\n   \nno equals sign here\nexpr = a = b\n";
+        assert_eq!(
+            parse_key_values(input),
+            vec![("name", "ferris"), ("expr", "a = b")]
+        );
+    }
+
+    #[test]
+    fn missing_key() {
+        let input = "=value\n  = x\nname = ferris\n";
+        assert_eq!(parse_key_values(input), vec![("name", "ferris")]);
+    }
+
+    #[test]
+    fn empty_input() {
+        assert!(parse_key_values("").is_empty());
+    }
+
+    #[test]
+    fn borrows_from_input() {
+        let input = String::from("key = value");
+        let pairs = parse_key_values(&input);
+        let (key, _) = pairs[0];
+        // The key is a view into `input`, not a copy of it.
+        assert_eq!(key.as_ptr(), input.as_ptr());
+    }
+}
@@ -0,0 +1,45 @@
+//! Combining iterators with `zip` and `enumerate`.
+
+/// Pair up `labels` and `values`, numbering each pair: `"0: name=value"`.
+///
+/// `zip` walks two iterators in lockstep, yielding a tuple from each step, and
+/// stops as soon as *either* one runs out. So if the slices have different
+/// lengths, the extra elements of the longer one are silently dropped---handy
+/// when that's what you want, and a quiet source of bugs when it isn't. (If
+/// the lengths should match, it's worth checking up front.) `enumerate` then
+/// wraps each item with its index, counting from zero.
+pub fn label_pairs(labels: &[&str], values: &[i32]) -> Vec<String> {
+    labels
+        .iter()
+        .zip(values)
+        .enumerate()
+        .map(|(index, (label, value))| format!("{}: {}={}", index, label, value))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equal_lengths() {
+        assert_eq!(
+            label_pairs(&["width", "height"], &[640, 480]),
+            vec!["0: width=640", "1: height=480"]
+        );
+    }
+
+    #[test]
+    fn fewer_labels() {
+        assert_eq!(label_pairs(&["x"], &[1, 2, 3]), vec!["0: x=1"]);
+    }
+
+    #[test]
+    fn fewer_values() {
+        assert_eq!(
+            label_pairs(&["a", "b", "c"], &[10, 20]),
+            vec!["0: a=10", "1: b=20"]
+        );
+        assert_eq!(label_pairs(&["a"], &[]), Vec::<String>::new());
+    }
+}
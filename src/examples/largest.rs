@@ -0,0 +1,53 @@
+//! Generics with trait bounds: finding the largest item in a slice.
+
+/// Get the largest element of `list`, or `None` if it's empty.
+///
+/// The bounds say exactly what we need from `T`, and no more:
+///
+///   - `PartialOrd`, so we can compare elements with `>`. (Not `Ord`, so that
+///     this works with `f64`, which only has a *partial* order because of NaN.)
+///   - `Copy`, so we can pull an element out of the borrowed slice and hand it
+///     back by value. Without `Copy`, we couldn't move out of `list[i]`; we'd
+///     have to either `Clone` it (which can be expensive) or return a
+///     reference instead: `fn largest<T: PartialOrd>(list: &[T]) -> Option<&T>`.
+///     That version works for *any* `T`, including `String`, at the cost of
+///     tying the result's lifetime to the slice. For small `Copy` types like
+///     numbers and `char`s, returning the value is simpler to use.
+pub fn largest<T: PartialOrd + Copy>(list: &[T]) -> Option<T> {
+    let mut items = list.iter();
+    let mut largest = *items.next()?;
+    for &item in items {
+        if item > largest {
+            largest = item;
+        }
+    }
+
+    Some(largest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers() {
+        assert_eq!(largest(&[34, 50, 25, 100, 65]), Some(100));
+        assert_eq!(largest(&[-3, -1, -2]), Some(-1));
+    }
+
+    #[test]
+    fn floats() {
+        assert_eq!(largest(&[1.5, -2.0, 3.25, 0.0]), Some(3.25));
+    }
+
+    #[test]
+    fn chars() {
+        assert_eq!(largest(&['y', 'm', 'a', 'q']), Some('y'));
+    }
+
+    #[test]
+    fn empty() {
+        let empty: [i32; 0] = [];
+        assert_eq!(largest(&empty), None);
+    }
+}
@@ -0,0 +1,59 @@
+//! Lazily initialized statics with `OnceLock`.
+//!
+//! A `static` initializer has to be a constant expression, so it can't build
+//! a `HashMap`. `std::sync::OnceLock` gets around that: the static holds an
+//! empty cell, and the first call to `get_or_init` runs the initializer and
+//! stores the result. It's thread-safe---if several threads race to be first,
+//! exactly one of them runs the initializer, and the others block until it's
+//! done---and every later call just hands back a reference to the stored
+//! value. Before `OnceLock` was stabilized (in Rust 1.70), this was what the
+//! [lazy_static] and [once_cell] crates were for.
+//!
+//! [lazy_static]: https://crates.io/crates/lazy_static
+//! [once_cell]: https://crates.io/crates/once_cell
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+static CONFIG: OnceLock<HashMap<String, String>> = OnceLock::new();
+
+/// The application's configuration, built the first time it's asked for.
+///
+/// Since the map lives in a `static`, it's never dropped, and we can hand out
+/// `'static` references to it.
+pub fn config() -> &'static HashMap<String, String> {
+    CONFIG.get_or_init(|| {
+        let mut config = HashMap::new();
+        config.insert(String::from("host"), String::from("localhost"));
+        config.insert(String::from("port"), String::from("8080"));
+        config
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn contents() {
+        assert_eq!(config()["host"], "localhost");
+        assert_eq!(config().get("port").map(String::as_str), Some("8080"));
+    }
+
+    #[test]
+    fn initialized_only_once() {
+        let first: *const _ = config();
+        let second: *const _ = config();
+        assert!(std::ptr::eq(first, second));
+    }
+
+    #[test]
+    fn shared_across_threads() {
+        let here = config() as *const _ as usize;
+        let there = thread::spawn(|| config() as *const _ as usize)
+            .join()
+            .unwrap();
+        assert_eq!(here, there);
+    }
+}
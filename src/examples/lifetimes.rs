@@ -0,0 +1,70 @@
+//! Lifetimes: the canonical `longest` example.
+//!
+//! See [e013] for the full discussion of lifetimes; this is just the example
+//! everyone meets first, in runnable form.
+//!
+//! [e013]: /show_notes/e013/index.html
+
+/// Get whichever of two string slices is longer.
+///
+/// The lifetime annotation says the returned reference lives as long as
+/// *both* of the inputs---the compiler can't know which one we'll return, so
+/// it has to assume it could be either. If the lengths are equal, we return
+/// the first one.
+pub fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    if y.len() > x.len() {
+        y
+    } else {
+        x
+    }
+}
+
+/// A struct which holds a reference, and therefore needs a lifetime.
+///
+/// An `Excerpt` cannot outlive the text it points into.
+pub struct Excerpt<'a> {
+    pub part: &'a str,
+}
+
+impl<'a> Excerpt<'a> {
+    /// Get the excerpted text.
+    ///
+    /// Here, lifetime elision ties the returned `&str` to `&self`, which is
+    /// fine; we could also return `&'a str` to tie it to the original text.
+    pub fn part(&self) -> &str {
+        self.part
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn longest_picks_longer() {
+        assert_eq!(longest("hello", "hi"), "hello");
+        assert_eq!(longest("hi", "hello"), "hello");
+    }
+
+    #[test]
+    fn longest_with_equal_lengths_picks_first() {
+        assert_eq!(longest("abc", "xyz"), "abc");
+    }
+
+    #[test]
+    fn longest_with_empty_strings() {
+        assert_eq!(longest("", "a"), "a");
+        assert_eq!(longest("a", ""), "a");
+        assert_eq!(longest("", ""), "");
+    }
+
+    #[test]
+    fn excerpt_part() {
+        let text = String::from("Call me Ishmael. Some years ago...");
+        let first_sentence = text.split('.').next().unwrap();
+        let excerpt = Excerpt {
+            part: first_sentence,
+        };
+        assert_eq!(excerpt.part(), "Call me Ishmael");
+    }
+}
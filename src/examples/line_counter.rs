@@ -0,0 +1,55 @@
+//! Error propagation with `Result` and the `?` operator.
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+/// Count the lines in the file at `path`.
+///
+/// Each `?` says: if this is an `Err`, return it from the function right now;
+/// otherwise, unwrap the `Ok` value and keep going. Both opening the file and
+/// reading each line can fail, and both failures are `io::Error`s, so they
+/// line up with our `io::Result` return type without any conversion.
+pub fn count_lines(path: &Path) -> io::Result<usize> {
+    let file = File::open(path)?;
+    let mut count = 0;
+    for line in BufReader::new(file).lines() {
+        line?;
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs;
+    use std::io::{ErrorKind, Write};
+
+    #[test]
+    fn counts_lines_in_a_file() {
+        let path = env::temp_dir().join(format!(
+            "show-notes-line-counter-{}.txt",
+            std::process::id()
+        ));
+        {
+            let mut file = File::create(&path).unwrap();
+            writeln!(file, "one").unwrap();
+            writeln!(file, "two").unwrap();
+            write!(file, "three, without a trailing newline").unwrap();
+        }
+
+        let result = count_lines(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[test]
+    fn missing_file_is_not_found() {
+        let path = Path::new("/this/path/really/should/not/exist.txt");
+        let err = count_lines(path).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+    }
+}
@@ -0,0 +1,142 @@
+//! Declarative macros: repetition and recursion.
+//!
+//! See [e010] for the basics of `macro_rules!`.
+//!
+//! [e010]: /show_notes/e010/index.html
+
+/// Build a `HashMap` from `key => value` pairs, like `vec!` does for `Vec`s.
+///
+/// ```rust
+/// # #[macro_use] extern crate show_notes;
+/// # fn main() {
+/// let legs = hashmap! {
+///     "ferris" => 10,
+///     "gopher" => 4,
+/// };
+/// assert_eq!(legs["ferris"], 10);
+/// # }
+/// ```
+///
+/// The pattern, `$($key:expr => $value:expr),* $(,)?`, has two pieces:
+///
+///   - `$( ... ),*` matches whatever is inside the parentheses zero or more
+///     times, *separated* by commas. Every `$key` and `$value` matched along
+///     the way is remembered, and `$( ... )*` in the expansion repeats its
+///     contents once for each of them, in order.
+///   - Since the comma is a *separator*, that alone wouldn't accept a comma
+///     after the last pair. `$(,)?` matches an optional trailing comma---`?`
+///     meaning "zero or one times"---so both styles work.
+///
+/// Note the double braces in the expansion: the outer pair delimits the
+/// macro's output, and the inner pair makes that output a single block
+/// *expression*, so `let map = hashmap!{...};` works.
+#[macro_export]
+macro_rules! hashmap {
+    ($($key:expr => $value:expr),* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut map = ::std::collections::HashMap::new();
+        $(
+            map.insert($key, $value);
+        )*
+        map
+    }};
+}
+
+/// Group a list of values into a `Vec` of pairs: `pairs!(1, 2, 3, 4)` is
+/// `vec![(1, 2), (3, 4)]`.
+///
+/// ```rust
+/// # #[macro_use] extern crate show_notes;
+/// # fn main() {
+/// assert_eq!(pairs!(1, 2, 3, 4), vec![(1, 2), (3, 4)]);
+/// # }
+/// ```
+///
+/// Unlike `hashmap!`, we can't do this with a single repetition, because we
+/// need to take the input *two at a time*. Instead, the macro calls itself
+/// recursively, peeling off two expressions per step and adding them to an
+/// accumulator (the `[...]` carried along after the internal `@acc` marker).
+/// When the input runs out, it emits everything accumulated inside `vec![]`.
+/// So `pairs!(1, 2, 3, 4)` expands step by step like this:
+///
+/// ```text
+/// pairs!(@acc [] 1, 2, 3, 4)
+/// pairs!(@acc [(1, 2),] 3, 4)
+/// pairs!(@acc [(1, 2), (3, 4),])
+/// vec![(1, 2), (3, 4),]
+/// ```
+///
+/// With an odd number of arguments, we eventually end up with a single
+/// expression left over, which the `compile_error!` arm catches:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate show_notes;
+/// # fn main() {
+/// let oops = pairs!(1, 2, 3);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! pairs {
+    (@acc [$($acc:tt)*]) => {
+        vec![$($acc)*]
+    };
+    (@acc [$($acc:tt)*] $a:expr, $b:expr) => {
+        $crate::pairs!(@acc [$($acc)* ($a, $b),])
+    };
+    (@acc [$($acc:tt)*] $a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::pairs!(@acc [$($acc)* ($a, $b),] $($rest)*)
+    };
+    (@acc [$($acc:tt)*] $a:expr) => {
+        compile_error!("pairs! needs an even number of arguments")
+    };
+    ($($items:tt)*) => {
+        $crate::pairs!(@acc [] $($items)*)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    #[test]
+    fn integer_values() {
+        let map = hashmap! { "a" => 1, "b" => 2 };
+        assert_eq!(map.len(), 2);
+        assert_eq!(map["a"], 1);
+        assert_eq!(map["b"], 2);
+    }
+
+    #[test]
+    fn string_values_with_trailing_comma() {
+        let map = hashmap! {
+            1 => String::from("one"),
+            2 => String::from("two"),
+        };
+        assert_eq!(map[&1], "one");
+        assert_eq!(map[&2], "two");
+    }
+
+    #[test]
+    fn empty() {
+        let map: HashMap<&str, i32> = hashmap! {};
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn pairs_of_numbers() {
+        assert_eq!(pairs!(1, 2), vec![(1, 2)]);
+        assert_eq!(pairs!(1, 2, 3, 4, 5, 6), vec![(1, 2), (3, 4), (5, 6)]);
+    }
+
+    #[test]
+    fn pairs_of_expressions() {
+        let x = 10;
+        assert_eq!(pairs!("a", x + 1, "b", x * 2), vec![("a", 11), ("b", 20)]);
+    }
+
+    #[test]
+    fn no_pairs() {
+        let empty: Vec<(i32, i32)> = pairs!();
+        assert!(empty.is_empty());
+    }
+}
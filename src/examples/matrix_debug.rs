@@ -0,0 +1,75 @@
+//! A hand-written `std::fmt::Debug` implementation.
+//!
+//! `#[derive(Debug)]` would give us this for `{:?}`:
+//!
+//! ```text
+//! Matrix2x2 { data: [[1.0, 2.0], [3.0, 4.0]] }
+//! ```
+//!
+//! ...and for the "pretty" `{:#?}`, every single number on its own line, which
+//! is (technically) complete but not much help for seeing the shape of a
+//! matrix. The derived version is always a faithful, field-by-field dump of the
+//! struct; writing `Debug` ourselves lets us show the value the way we actually
+//! *think* about it. The `Formatter` tells us which form was asked for via
+//! `f.alternate()`.
+
+use std::fmt;
+
+/// A 2×2 matrix.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Matrix2x2 {
+    pub data: [[f64; 2]; 2],
+}
+
+impl fmt::Debug for Matrix2x2 {
+    /// `{:?}` prints the matrix on one line, and `{:#?}` prints it as a grid,
+    /// with the columns right-aligned.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !f.alternate() {
+            return write!(f, "Matrix2x2 {:?}", self.data);
+        }
+
+        let cells = self.data.map(|row| row.map(|value| format!("{:?}", value)));
+        let width = |col: usize| cells.iter().map(|row| row[col].len()).max().unwrap_or(0);
+        let (left, right) = (width(0), width(1));
+
+        writeln!(f, "Matrix2x2 [")?;
+        for [a, b] in &cells {
+            writeln!(
+                f,
+                "    [{:>left$}, {:>right$}]",
+                a,
+                b,
+                left = left,
+                right = right
+            )?;
+        }
+        write!(f, "]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compact() {
+        let m = Matrix2x2 {
+            data: [[1.0, 2.0], [3.0, 4.0]],
+        };
+        assert_eq!(format!("{:?}", m), "Matrix2x2 [[1.0, 2.0], [3.0, 4.0]]");
+    }
+
+    #[test]
+    fn pretty() {
+        let m = Matrix2x2 {
+            data: [[1.0, -20.5], [300.25, 4.0]],
+        };
+        let expected = "\
+Matrix2x2 [
+    [   1.0, -20.5]
+    [300.25,   4.0]
+]";
+        assert_eq!(format!("{:#?}", m), expected);
+    }
+}
@@ -0,0 +1,88 @@
+//! Moving values out of `&mut` with `mem::take`, `mem::replace`, and
+//! `mem::swap`.
+//!
+//! Given only a `&mut String`, we can't just move the `String` out: that would
+//! leave the owner holding a reference to nothing, and the borrow checker
+//! rejects it (`error[E0507]: cannot move out of *slot which is behind a
+//! mutable reference`). What we *can* do is move it out while putting
+//! something else in its place, in the same operation, so there's never a
+//! moment where the slot is empty. These three functions are all variations
+//! on that theme. They're especially handy for fields of a struct behind
+//! `&mut self`, like taking the contents of a buffer to hand off elsewhere.
+
+use std::mem;
+
+/// Take the string out of `slot`, leaving an empty one behind.
+///
+/// `mem::take` puts in the type's `Default` value---for a `String`, that's
+/// empty, which doesn't allocate.
+pub fn take_and_reset(slot: &mut String) -> String {
+    mem::take(slot)
+}
+
+/// Put `new` in `slot`, and return the value which was there before.
+///
+/// `mem::replace` is the general version of `take`, for when there's no
+/// sensible default, or it isn't the value you want to leave behind.
+pub fn replace_value(slot: &mut i32, new: i32) -> i32 {
+    mem::replace(slot, new)
+}
+
+/// Exchange the contents of `a` and `b`.
+///
+/// Nothing is returned: `mem::swap` just trades the two values over. (The
+/// textbook `let tmp = *a; *a = *b; *b = tmp;` doesn't compile for a
+/// `String`, since the first two steps each move out of a reference.)
+pub fn swap_strings(a: &mut String, b: &mut String) {
+    mem::swap(a, b);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn take() {
+        let mut slot = String::from("contents");
+        let taken = take_and_reset(&mut slot);
+        assert_eq!(taken, "contents");
+        assert_eq!(slot, "");
+    }
+
+    #[test]
+    fn replace() {
+        let mut slot = 1;
+        assert_eq!(replace_value(&mut slot, 2), 1);
+        assert_eq!(slot, 2);
+        assert_eq!(replace_value(&mut slot, 3), 2);
+        assert_eq!(slot, 3);
+    }
+
+    #[test]
+    fn swap() {
+        let mut left = String::from("left");
+        let mut right = String::from("right");
+        swap_strings(&mut left, &mut right);
+        assert_eq!((left.as_str(), right.as_str()), ("right", "left"));
+    }
+
+    #[test]
+    fn taking_a_field() {
+        struct Buffer {
+            pending: Vec<u8>,
+        }
+
+        impl Buffer {
+            fn flush(&mut self) -> Vec<u8> {
+                // `self.pending` on its own would be a move out of `&mut self`.
+                mem::take(&mut self.pending)
+            }
+        }
+
+        let mut buffer = Buffer {
+            pending: vec![1, 2, 3],
+        };
+        assert_eq!(buffer.flush(), vec![1, 2, 3]);
+        assert_eq!(buffer.flush(), vec![]);
+    }
+}
@@ -0,0 +1,76 @@
+//! Memoization: caching results in a `HashMap`.
+//!
+//! The naive recursive Fibonacci in the [`fib_bench`] example is slow because
+//! it computes the same values again and again. Memoizing it---remembering
+//! each result the first time we compute it---keeps the simple recursive
+//! structure but makes it O(n), since each value is only ever computed once.
+//! The price is memory: the cache holds on to every value it has computed,
+//! for as long as the `Memo` is alive. That's the usual trade, and whether it's
+//! worth it depends on how often the same inputs come up again. (When, as here,
+//! each value only depends on the previous two, the iterative version gets the
+//! same speed in constant space.)
+//!
+//! [`fib_bench`]: ../fib_bench/index.html
+
+use std::collections::HashMap;
+
+/// A Fibonacci calculator which remembers its previous answers.
+pub struct Memo {
+    cache: HashMap<u32, u64>,
+}
+
+impl Memo {
+    pub fn new() -> Memo {
+        Memo {
+            cache: HashMap::new(),
+        }
+    }
+
+    /// The `n`th Fibonacci number.
+    ///
+    /// This takes `&mut self` because even a lookup may need to add to the
+    /// cache.
+    pub fn fib(&mut self, n: u32) -> u64 {
+        if let Some(&cached) = self.cache.get(&n) {
+            return cached;
+        }
+        let result = match n {
+            0 | 1 => u64::from(n),
+            _ => self.fib(n - 1) + self.fib(n - 2),
+        };
+        self.cache.insert(n, result);
+        result
+    }
+}
+
+impl Default for Memo {
+    fn default() -> Memo {
+        Memo::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::fib_bench::fib_iterative;
+
+    #[test]
+    fn matches_iterative() {
+        let mut memo = Memo::new();
+        for n in 0..=90 {
+            assert_eq!(memo.fib(n), fib_iterative(n), "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn repeat_calls_hit_the_cache() {
+        let mut memo = Memo::new();
+        assert_eq!(memo.fib(30), 832_040);
+        // Computing `fib(30)` filled in every value from 0 to 30 along the way.
+        assert_eq!(memo.cache.len(), 31);
+
+        assert_eq!(memo.fib(30), 832_040);
+        assert_eq!(memo.fib(12), 144);
+        assert_eq!(memo.cache.len(), 31);
+    }
+}
@@ -0,0 +1,93 @@
+//! Examples: small, self-contained code samples to study alongside the show.
+
+pub mod age;
+pub mod binary_search;
+pub mod blanket_impls;
+pub mod builder;
+pub mod button;
+pub mod calc_error;
+pub mod catch_panic;
+pub mod cell_counter;
+pub mod channel_sum;
+pub mod clamp;
+pub mod classify;
+pub mod cli_flags;
+pub mod closures;
+pub mod const_eval;
+pub mod coordinate_keys;
+pub mod dedup;
+pub mod drain;
+pub mod drop_order;
+pub mod elision;
+pub mod entry_counter;
+pub mod even_squares;
+pub mod expr;
+pub mod ffi_abs;
+pub mod fib_bench;
+pub mod fibonacci;
+pub mod first_chars;
+pub mod flattening;
+pub mod fn_pointers;
+pub mod greet;
+pub mod grid;
+pub mod group_averages;
+pub mod header_body;
+pub mod histogram;
+pub mod key_values;
+pub mod label_pairs;
+pub mod largest;
+pub mod lazy_config;
+pub mod lifetimes;
+pub mod line_counter;
+pub mod macros;
+pub mod matrix_debug;
+pub mod mem_ops;
+pub mod memo;
+pub mod money;
+pub mod moving_average;
+pub mod mutex_counter;
+pub mod my_box;
+pub mod nested_lookup;
+pub mod newtype_display;
+pub mod option_sugar;
+pub mod ordered_maps;
+pub mod overflow;
+pub mod ownership_demo;
+pub mod panic_hook;
+pub mod parse_radix;
+pub mod partition;
+pub mod point_json;
+pub mod raw_swap;
+pub mod report;
+pub mod retain;
+pub mod reverse;
+pub mod run_length;
+pub mod running_stats;
+pub mod running_totals;
+pub mod sanitize;
+pub mod scalar_count;
+pub mod scoped_threads;
+pub mod set_relations;
+pub mod shapes;
+pub mod shared_tree;
+pub mod sliding_max;
+pub mod sorting;
+pub mod split_halves;
+pub mod stack;
+pub mod step_by;
+pub mod stepped_range;
+pub mod sum_lines;
+pub mod supertraits;
+pub mod temperature;
+pub mod time_it;
+pub mod top_k;
+pub mod traffic_light;
+pub mod typed_ids;
+pub mod typestate;
+pub mod units;
+pub mod vector2d;
+pub mod version;
+pub mod visitor;
+pub mod weak_parent;
+pub mod where_clauses;
+pub mod word_count;
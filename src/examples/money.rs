@@ -0,0 +1,107 @@
+//! A `Display` implementation which respects formatting flags.
+
+use std::fmt;
+
+/// An amount of money, stored as a whole number of cents.
+///
+/// (Never store money as a float! `0.1 + 0.2 != 0.3`, and your accountant
+/// will notice.)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Money {
+    pub cents: i64,
+}
+
+impl fmt::Display for Money {
+    /// Format as dollars: `$12.34`.
+    ///
+    /// The `Formatter` carries whatever flags the caller used, so
+    /// `f.precision()` is `Some(2)` for `{:.2}`, and `None` for a plain `{}`,
+    /// in which case we use two decimal places anyway.
+    ///
+    /// Negative amounts get the sign *outside* the dollar sign, `-$1.00`, as
+    /// is conventional. If we just formatted `cents / 100` as a signed number
+    /// after a `$`, we'd get `$-1.00` instead; so we print the sign ourselves,
+    /// and then format the *absolute* value. (`unsigned_abs` rather than `abs`,
+    /// since `i64::MIN.abs()` overflows.)
+    ///
+    /// All the arithmetic stays in integers: converting to a float would throw
+    /// away exactly the exactness we stored cents to get, and an `f64` can't
+    /// even represent every `i64` once amounts pass 2⁵³ cents. A precision
+    /// below 2 rounds to the nearest dime or dollar (halves round up); a
+    /// precision above 2 just pads with zeros, since there's nothing smaller
+    /// than a cent to show.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let precision = f.precision().unwrap_or(2);
+        let sign = if self.cents < 0 { "-" } else { "" };
+        let abs = self.cents.unsigned_abs();
+        match precision {
+            0 => write!(f, "{}${}", sign, abs / 100 + u64::from(abs % 100 >= 50)),
+            1 => {
+                let dimes = abs / 10 + u64::from(abs % 10 >= 5);
+                write!(f, "{}${}.{}", sign, dimes / 10, dimes % 10)
+            }
+            _ => write!(
+                f,
+                "{}${}.{:02}{:0<pad$}",
+                sign,
+                abs / 100,
+                abs % 100,
+                "",
+                pad = precision - 2
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero() {
+        assert_eq!(format!("{:.2}", Money { cents: 0 }), "$0.00");
+    }
+
+    #[test]
+    fn round_dollars() {
+        assert_eq!(format!("{:.2}", Money { cents: 500 }), "$5.00");
+        assert_eq!(format!("{:.0}", Money { cents: 500 }), "$5");
+    }
+
+    #[test]
+    fn dollars_and_cents() {
+        assert_eq!(format!("{:.2}", Money { cents: 1234 }), "$12.34");
+        assert_eq!(format!("{}", Money { cents: 1234 }), "$12.34");
+        assert_eq!(format!("{:.3}", Money { cents: 1234 }), "$12.340");
+    }
+
+    #[test]
+    fn negative() {
+        assert_eq!(format!("{:.2}", Money { cents: -100 }), "-$1.00");
+        assert_eq!(format!("{:.2}", Money { cents: -5 }), "-$0.05");
+    }
+
+    #[test]
+    fn rounding() {
+        assert_eq!(format!("{:.0}", Money { cents: 1249 }), "$12");
+        assert_eq!(format!("{:.0}", Money { cents: 1250 }), "$13");
+        assert_eq!(format!("{:.1}", Money { cents: 1234 }), "$12.3");
+        assert_eq!(format!("{:.1}", Money { cents: 1996 }), "$20.0");
+        assert_eq!(format!("{:.1}", Money { cents: -1996 }), "-$20.0");
+    }
+
+    #[test]
+    fn exact_beyond_f64_precision() {
+        // 2⁵³ + 1 cents can't be represented as an `f64`.
+        let cents = (1_i64 << 53) + 1;
+        assert_eq!(Money { cents }.to_string(), "$90071992547409.93");
+        assert_eq!(
+            Money { cents: i64::MIN }.to_string(),
+            "-$92233720368547758.08"
+        );
+        assert_eq!(
+            Money { cents: i64::MAX }.to_string(),
+            "$92233720368547758.07"
+        );
+    }
+}
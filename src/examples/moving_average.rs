@@ -0,0 +1,53 @@
+//! Slice views with `windows` (and `chunks`): a moving average.
+//!
+//! Both `windows` and `chunks` hand out sub-slices which *borrow* from the
+//! original data; neither copies anything. The difference is in how the views
+//! relate to each other:
+//!
+//!   - `windows(3)` over `[1, 2, 3, 4]` yields `[1, 2, 3]` and `[2, 3, 4]`:
+//!     each view starts one element after the last, so they *overlap*. That's
+//!     fine, because they're all shared (`&`) borrows---which is also why
+//!     there's no `windows_mut`: overlapping `&mut` views would let you
+//!     modify the same element through two references at once.
+//!   - `chunks(3)` yields `[1, 2, 3]` and `[4]`: the views never overlap, and
+//!     the last one may be short. Since they're disjoint, `chunks_mut` exists.
+
+/// Average each run of `window` consecutive values.
+///
+/// Returns an empty vector when `window` is zero (`windows(0)` would panic)
+/// or larger than the data (there are no complete windows).
+pub fn moving_average(data: &[f64], window: usize) -> Vec<f64> {
+    if window == 0 || window > data.len() {
+        return Vec::new();
+    }
+
+    data.windows(window)
+        .map(|w| w.iter().sum::<f64>() / window as f64)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simple_series() {
+        assert_eq!(
+            moving_average(&[1.0, 2.0, 3.0, 4.0, 5.0], 3),
+            vec![2.0, 3.0, 4.0]
+        );
+        assert_eq!(moving_average(&[1.0, 2.0], 1), vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn window_equal_to_length() {
+        assert_eq!(moving_average(&[2.0, 4.0, 6.0], 3), vec![4.0]);
+    }
+
+    #[test]
+    fn degenerate_windows() {
+        assert!(moving_average(&[1.0, 2.0], 3).is_empty());
+        assert!(moving_average(&[1.0, 2.0], 0).is_empty());
+        assert!(moving_average(&[], 1).is_empty());
+    }
+}
@@ -0,0 +1,57 @@
+//! Shared-state concurrency with `Arc<Mutex<T>>`.
+//!
+//! This is the threaded cousin of `Rc<RefCell<T>>`: `Arc` is an *atomically*
+//! reference-counted pointer, safe to share across threads, and `Mutex` gives
+//! us mutation through a shared reference, by making each thread wait its turn.
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Spawn `threads` threads which each increment a shared counter `per_thread`
+/// times, and return the final count.
+///
+/// `counter.lock()` blocks until this thread has the lock, then returns a
+/// guard which derefs to the `usize` inside. The lock is released when the
+/// guard is dropped---at the end of the statement here.
+///
+/// `lock` returns a `Result` because of *poisoning*: if a thread panics while
+/// holding the lock, the data might have been left half-updated, so every
+/// later `lock` returns an `Err` to warn you. Calling `unwrap` means "if
+/// another thread panicked mid-update, panic here too", which is usually the
+/// right call.
+pub fn parallel_increment(threads: usize, per_thread: usize) -> usize {
+    let counter = Arc::new(Mutex::new(0));
+
+    let handles: Vec<_> = (0..threads)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+            thread::spawn(move || {
+                for _ in 0..per_thread {
+                    *counter.lock().unwrap() += 1;
+                }
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let total = *counter.lock().unwrap();
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_every_increment() {
+        assert_eq!(parallel_increment(8, 1000), 8000);
+    }
+
+    #[test]
+    fn no_threads() {
+        assert_eq!(parallel_increment(0, 1000), 0);
+    }
+}
@@ -0,0 +1,62 @@
+//! `Deref` coercion with a smart-pointer newtype.
+//!
+//! See also [e018], which covers `Deref` alongside `AsRef` and `Borrow`.
+//!
+//! [e018]: /show_notes/e018/index.html
+
+use std::ops::Deref;
+
+/// A (very) minimal smart pointer. Unlike a real `Box`, it keeps its contents
+/// on the stack; the point is just to implement `Deref`.
+pub struct MyBox<T>(pub T);
+
+impl<T> MyBox<T> {
+    pub fn new(x: T) -> MyBox<T> {
+        MyBox(x)
+    }
+}
+
+impl<T> Deref for MyBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+/// Greet someone by name. Takes a plain `&str`.
+pub fn hello(name: &str) -> String {
+    format!("Hello, {}!", name)
+}
+
+/// Call `hello` with a `&MyBox<String>`, which is *not* a `&str`!
+///
+/// This works because of deref coercion: when the types don't match, the
+/// compiler will insert as many calls to `deref` as it needs to make them
+/// match. Here it takes two steps:
+///
+///   1. `&MyBox<String>` → `&String`, via our `Deref` impl above.
+///   2. `&String` → `&str`, via the standard library's `Deref` for `String`.
+///
+/// Without coercion, we'd have to write `hello(&(*name)[..])` ourselves.
+pub fn hello_boxed(name: &MyBox<String>) -> String {
+    hello(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explicit_deref() {
+        let x = MyBox(5);
+        assert_eq!(*x, 5);
+    }
+
+    #[test]
+    fn deref_coercion() {
+        let name = MyBox::new(String::from("Ferris"));
+        assert_eq!(hello(&name), "Hello, Ferris!");
+        assert_eq!(hello_boxed(&name), "Hello, Ferris!");
+    }
+}
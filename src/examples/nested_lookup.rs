@@ -0,0 +1,57 @@
+//! The `?` operator with `Option`.
+
+use std::collections::HashMap;
+
+/// Look up `field` in the record for `user`.
+///
+/// `?` isn't just for `Result`. In a function which returns an `Option`,
+/// applying `?` to an `Option` either unwraps a `Some` or returns `None` from
+/// the whole function, right there---so a chain of lookups, any of which might
+/// come up empty, reads as straight-line code instead of nested `match`es or
+/// `and_then` closures. (What `?` won't do is convert between the two: using it
+/// on an `Option` in a function returning a `Result` needs an explicit
+/// `.ok_or(...)` first.)
+pub fn lookup_city(
+    data: &HashMap<String, HashMap<String, String>>,
+    user: &str,
+    field: &str,
+) -> Option<String> {
+    let record = data.get(user)?;
+    let value = record.get(field)?;
+    Some(value.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data() -> HashMap<String, HashMap<String, String>> {
+        let mut alice = HashMap::new();
+        alice.insert(String::from("city"), String::from("Wellington"));
+        alice.insert(String::from("country"), String::from("New Zealand"));
+
+        let mut data = HashMap::new();
+        data.insert(String::from("alice"), alice);
+        data.insert(String::from("bob"), HashMap::new());
+        data
+    }
+
+    #[test]
+    fn present() {
+        assert_eq!(
+            lookup_city(&data(), "alice", "city"),
+            Some(String::from("Wellington"))
+        );
+    }
+
+    #[test]
+    fn missing_user() {
+        assert_eq!(lookup_city(&data(), "carol", "city"), None);
+    }
+
+    #[test]
+    fn missing_field() {
+        assert_eq!(lookup_city(&data(), "alice", "postcode"), None);
+        assert_eq!(lookup_city(&data(), "bob", "city"), None);
+    }
+}
@@ -0,0 +1,40 @@
+//! The newtype pattern, for getting around the orphan rule.
+//!
+//! The *orphan rule* (discussed in [e023]) says we can only implement a trait
+//! for a type if our crate defines the trait, the type, or both. So we can't
+//! write `impl Display for Vec<String>`: both are the standard library's. If
+//! that were allowed, two crates could each write their own version of the same
+//! impl, and there'd be no way to pick between them when both were used in one
+//! program. Wrapping the foreign type in a local one---a *newtype*, as in
+//! [e021]---gives us a type of our own to implement the trait on, at no
+//! runtime cost.
+//!
+//! [e021]: /show_notes/e021/index.html
+//! [e023]: /show_notes/e023/index.html
+
+use std::fmt::{self, Display};
+
+/// A list of strings which displays as a comma-separated list.
+pub struct Wrapper(pub Vec<String>);
+
+impl Display for Wrapper {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}]", self.0.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn joins_with_commas() {
+        let wrapper = Wrapper(vec![String::from("hello"), String::from("world")]);
+        assert_eq!(wrapper.to_string(), "[hello, world]");
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Wrapper(vec![]).to_string(), "[]");
+    }
+}
@@ -0,0 +1,77 @@
+//! Three bits of sugar for working with `Option`: `if let`, `while let`, and
+//! `let else`.
+//!
+//! Each is shorthand for a `match` with one interesting arm:
+//!
+//!   - `if let` runs a block only when the pattern matches.
+//!   - `while let` keeps looping as long as the pattern matches.
+//!   - `let else` binds the pattern for the *rest of the function*, and runs
+//!     the `else` block (which must diverge: `return`, `break`, `panic!`...)
+//!     when it doesn't match.
+
+/// Get the first even number in `v`, if there is one.
+///
+/// `find` gives us an `Option<&i32>`; `if let` handles the `Some` case and
+/// ignores the `None`, without a `match` arm that does nothing. (Really,
+/// `v.iter().find(...).copied()` would do the whole job, but then there'd be
+/// no `if let` to look at.)
+pub fn first_even(v: &[i32]) -> Option<i32> {
+    if let Some(&x) = v.iter().find(|&&x| x % 2 == 0) {
+        return Some(x);
+    }
+    None
+}
+
+/// Pop everything off `stack`, returning the items in the order popped.
+///
+/// `pop` returns `Some(item)` until the stack is empty, then `None`, which is
+/// exactly the shape `while let` is made for.
+pub fn drain_stack(stack: &mut Vec<i32>) -> Vec<i32> {
+    let mut popped = Vec::with_capacity(stack.len());
+    while let Some(x) = stack.pop() {
+        popped.push(x);
+    }
+    popped
+}
+
+/// Get the configured value, or `"default"` if there isn't one.
+///
+/// (In real code, `opt.unwrap_or("default")` says the same thing in one
+/// line; `let else` really shines when there's more work to do with the
+/// unwrapped value afterward, without another level of nesting.)
+pub fn require_config(opt: Option<&str>) -> &str {
+    let Some(config) = opt else {
+        return "default";
+    };
+    config
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_first_even() {
+        assert_eq!(first_even(&[1, 3, 4, 6]), Some(4));
+        assert_eq!(first_even(&[-2, 1]), Some(-2));
+        assert_eq!(first_even(&[1, 3, 5]), None);
+        assert_eq!(first_even(&[]), None);
+    }
+
+    #[test]
+    fn drains_stack() {
+        let mut stack = vec![1, 2, 3];
+        assert_eq!(drain_stack(&mut stack), vec![3, 2, 1]);
+        assert!(stack.is_empty());
+
+        let mut empty = Vec::new();
+        assert_eq!(drain_stack(&mut empty), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn requires_config() {
+        assert_eq!(require_config(Some("custom")), "custom");
+        assert_eq!(require_config(Some("")), "");
+        assert_eq!(require_config(None), "default");
+    }
+}
@@ -0,0 +1,73 @@
+//! `BTreeMap` vs. `HashMap`: which one keeps things in order?
+//!
+//! Both map keys to values, and for most purposes they're interchangeable. The
+//! difference: a `HashMap` iterates in an arbitrary order (which can even
+//! change from run to run, since its hasher is randomly seeded), while a
+//! `BTreeMap` always iterates in sorted key order. In exchange, `HashMap`
+//! lookups are usually faster: O(1) on average, vs. O(log n).
+
+use std::collections::{BTreeMap, HashMap};
+
+/// Count the letters in `s`, in no particular order.
+pub fn hashed_letter_counts(s: &str) -> HashMap<char, u32> {
+    let mut counts = HashMap::new();
+    for c in s.chars().filter(|c| c.is_alphabetic()) {
+        *counts.entry(c).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Count the letters in `s`, ordered by letter.
+///
+/// Note that this is *identical* to `hashed_letter_counts` apart from the
+/// type of the map: the two have the same API for everything we use here.
+pub fn ordered_letter_counts(s: &str) -> BTreeMap<char, u32> {
+    let mut counts = BTreeMap::new();
+    for c in s.chars().filter(|c| c.is_alphabetic()) {
+        *counts.entry(c).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Get the letter counts for `s` as a list, sorted by letter.
+///
+/// Since a `BTreeMap` iterates in key order, we don't have to sort anything
+/// ourselves: we just collect. Doing the same with `hashed_letter_counts`
+/// would need an explicit `sort` afterward.
+pub fn sorted_letter_counts(s: &str) -> Vec<(char, u32)> {
+    ordered_letter_counts(s).into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorted_by_key() {
+        let counts = sorted_letter_counts("the quick brown fox jumps over the lazy dog");
+        let keys: Vec<char> = counts.iter().map(|&(c, _)| c).collect();
+        let mut sorted_keys = keys.clone();
+        sorted_keys.sort();
+        assert_eq!(keys, sorted_keys);
+        assert_eq!(counts.len(), 26);
+        assert_eq!(counts[0], ('a', 1));
+        assert_eq!(counts[14], ('o', 4));
+    }
+
+    #[test]
+    fn same_counts_either_way() {
+        let input = "Mississippi";
+        let hashed = hashed_letter_counts(input);
+        let ordered = ordered_letter_counts(input);
+        assert_eq!(hashed.len(), ordered.len());
+        for (c, n) in &ordered {
+            assert_eq!(hashed[c], *n);
+        }
+    }
+
+    #[test]
+    fn empty() {
+        assert!(sorted_letter_counts("").is_empty());
+        assert!(sorted_letter_counts("123 !?").is_empty());
+    }
+}
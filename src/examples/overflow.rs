@@ -0,0 +1,45 @@
+//! Handling integer overflow: checked, wrapping, and saturating arithmetic.
+//!
+//! With plain `+`, overflow panics in debug builds and silently wraps around
+//! in release builds (unless `overflow-checks` is turned on). Either way,
+//! it's treated as a bug. When overflow is something your code actually
+//! expects, the integer types have methods which make the behavior explicit
+//! and the same in every build.
+
+/// Add `a` and `b` three ways: `(checked, wrapping, saturating)`.
+///
+///   - `checked_add` returns `None` if the result doesn't fit, so the caller
+///     has to decide what to do about it.
+///   - `wrapping_add` wraps around modulo 2⁸ (for a `u8`): `255 + 1` is `0`.
+///     That's the right behavior for things like hashes and checksums.
+///   - `saturating_add` sticks at the limit: `255 + 1` is `255`. Good for
+///     things like volume levels or counters which shouldn't roll over.
+///
+/// (There's also `overflowing_add`, which returns the wrapped result *and* a
+/// `bool` saying whether it wrapped.)
+pub fn demo_overflow(a: u8, b: u8) -> (Option<u8>, u8, u8) {
+    (a.checked_add(b), a.wrapping_add(b), a.saturating_add(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_overflow() {
+        assert_eq!(demo_overflow(100, 50), (Some(150), 150, 150));
+    }
+
+    #[test]
+    fn exactly_max() {
+        assert_eq!(demo_overflow(200, 55), (Some(255), 255, 255));
+    }
+
+    #[test]
+    fn overflow() {
+        // 300 - 256 = 44
+        assert_eq!(demo_overflow(200, 100), (None, 44, 255));
+        assert_eq!(demo_overflow(u8::MAX, 1), (None, 0, u8::MAX));
+        assert_eq!(demo_overflow(u8::MAX, u8::MAX), (None, 254, u8::MAX));
+    }
+}
@@ -0,0 +1,77 @@
+//! Ownership: moving a value vs. borrowing it.
+//!
+//! A compact companion to [e002], which covers the same ground in much more
+//! detail. Read the [source] to see the two paths side by side.
+//!
+//! [e002]: /show_notes/e002/index.html
+//! [source]: /src/show_notes/examples/ownership_demo.rs.html
+
+/// Take ownership of a `String`, returning its length.
+///
+/// Once you pass a `String` to this function, it's *moved*: the function owns
+/// it now, and it's dropped when the function returns. The caller can't use it
+/// any more.
+pub fn take_ownership(s: String) -> usize {
+    s.len()
+}
+
+/// Borrow a `String`, returning its length.
+///
+/// The caller keeps ownership; we just get to look at it for a while. (In real
+/// code, you'd usually take `&str` instead of `&String`, since it's more
+/// flexible---but `&String` makes the parallel with `take_ownership` clearer.)
+#[allow(clippy::ptr_arg)]
+pub fn borrow_it(s: &String) -> usize {
+    s.len()
+}
+
+/// Show the difference between moving and borrowing.
+///
+/// If we *move* a `String` into `take_ownership`, we can't use it afterward.
+/// This won't compile, because `s` was moved on the first call:
+///
+/// ```compile_fail
+/// use show_notes::examples::ownership_demo::take_ownership;
+///
+/// let s = String::from("hello");
+/// let first = take_ownership(s);
+/// let second = take_ownership(s); // error[E0382]: use of moved value: `s`
+/// ```
+///
+/// Borrowing, on the other hand, leaves the caller with ownership, so we can
+/// borrow as many times as we like---which is what the body of this function
+/// does.
+pub fn demonstrate() -> (usize, usize) {
+    let s = String::from("hello");
+    let first = borrow_it(&s);
+    let second = borrow_it(&s);
+
+    // We still own `s`, so we can give it away at the very end.
+    let last = take_ownership(s);
+    assert_eq!(second, last);
+
+    (first, second)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn take_ownership_returns_length() {
+        assert_eq!(take_ownership(String::from("hello")), 5);
+    }
+
+    #[test]
+    fn borrow_it_returns_length() {
+        let s = String::from("hello, world");
+        assert_eq!(borrow_it(&s), 12);
+        // Still ours!
+        assert_eq!(s, "hello, world");
+    }
+
+    #[test]
+    fn demonstrate_borrows_twice() {
+        assert_eq!(demonstrate(), (5, 5));
+    }
+}
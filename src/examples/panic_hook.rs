@@ -0,0 +1,80 @@
+//! Custom panic hooks: keeping expected panics out of the output.
+//!
+//! When a thread panics, before any unwinding happens, Rust calls the *panic
+//! hook*. The default hook is what prints `thread 'main' panicked at ...` to
+//! stderr. `std::panic::set_hook` replaces it, and `take_hook` removes
+//! (and hands back) the current one.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Mutex, PoisonError};
+
+/// Held for as long as `with_silent_panic_hook` has the hook swapped out.
+static HOOK_LOCK: Mutex<()> = Mutex::new(());
+
+/// Run `f` with a panic hook which prints nothing, then restore the old hook.
+///
+/// This changes *process-wide* state. There is only one panic hook for the
+/// whole process, not one per thread, so while `f` runs, panics on every
+/// thread are silenced---including, say, other tests running in parallel.
+/// That's why restoring the previous hook matters: skip it, and every panic
+/// for the rest of the program's life disappears without a trace. And it has
+/// to happen even if `f` itself panics, which is why we catch the panic, put
+/// the hook back, and *then* carry on unwinding with `resume_unwind`.
+///
+/// Two overlapping calls (from different threads) would also race: the second
+/// could save the first one's silent hook as the "previous" one, and restore
+/// *that* at the end, silencing panics for good. So every call holds
+/// `HOOK_LOCK` from before taking the hook until after restoring it. Two
+/// details there: we release the lock *before* resuming the panic, so that it
+/// doesn't get poisoned; and we don't care if it was poisoned anyway, since
+/// the `()` it guards can't be left in a broken state.
+pub fn with_silent_panic_hook<F: FnOnce()>(f: F) {
+    let guard = HOOK_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+
+    panic::set_hook(previous);
+    drop(guard);
+
+    if let Err(payload) = result {
+        panic::resume_unwind(payload);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn silences_a_caught_panic() {
+        let mut caught = false;
+        with_silent_panic_hook(|| {
+            // This panic is caught, and thanks to the silent hook, nothing is
+            // printed to stderr either.
+            let result = panic::catch_unwind(|| panic!("shh"));
+            caught = result.is_err();
+        });
+        assert!(caught);
+    }
+
+    #[test]
+    fn runs_the_closure() {
+        let mut ran = false;
+        with_silent_panic_hook(|| ran = true);
+        assert!(ran);
+    }
+
+    #[test]
+    fn restores_the_hook_when_the_closure_panics() {
+        let result = panic::catch_unwind(|| with_silent_panic_hook(|| panic!("boom")));
+        assert!(result.is_err());
+        // The lock was released before the panic resumed, so it isn't
+        // poisoned, and the next call goes ahead normally.
+        let mut ran = false;
+        with_silent_panic_hook(|| ran = true);
+        assert!(ran);
+    }
+}
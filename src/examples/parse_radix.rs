@@ -0,0 +1,134 @@
+//! Parsing integers in other bases with `from_str_radix`.
+
+/// Split a `0x` (hexadecimal) or `0b` (binary) prefix off the front of `s`.
+///
+/// Returns the radix the prefix stands for, if there was one, and the rest of
+/// the string. Either prefix can be upper- or lowercase.
+pub fn split_radix_prefix(s: &str) -> (Option<u32>, &str) {
+    let prefix = s.get(..2).map(str::to_ascii_lowercase);
+    match prefix.as_deref() {
+        Some("0x") => (Some(16), &s[2..]),
+        Some("0b") => (Some(2), &s[2..]),
+        _ => (None, s),
+    }
+}
+
+/// Parse `s` as an integer in base `radix`.
+///
+/// A `0x` or `0b` prefix (after an optional `-` or `+`) overrides `radix`, so
+/// `parse_int("0xff", 10)` is 255. That does mean there's no way to parse
+/// `"0b1"` as a *hexadecimal* number, where `b` is a digit---drop the `0` if
+/// you need that.
+///
+/// `i64::from_str_radix` accepts any radix from 2 to 36: the digits `0` to `9`
+/// and then `a` to `z` (in either case), which is as far as the alphabet goes.
+/// Outside that range it *panics*, rather than returning an error, on the
+/// grounds that the radix is usually a constant and a bad one is a bug. Since
+/// ours comes in as an argument, we check it ourselves first.
+pub fn parse_int(s: &str, radix: u32) -> Result<i64, String> {
+    if !(2..=36).contains(&radix) {
+        return Err(format!("radix must be between 2 and 36 (got {})", radix));
+    }
+
+    // We have to deal with the sign ourselves, since it comes before the
+    // prefix. `from_str_radix` accepts a sign too, so once we've taken ours
+    // off, any sign left over is misplaced (like the one in `"0x-5"`), and we
+    // mustn't let it through.
+    let (sign, unsigned) = match s.as_bytes().first() {
+        Some(b'-') => ("-", &s[1..]),
+        Some(b'+') => ("", &s[1..]),
+        _ => ("", s),
+    };
+    let (prefix_radix, digits) = split_radix_prefix(unsigned);
+    let radix = prefix_radix.unwrap_or(radix);
+    if digits.starts_with(['+', '-']) {
+        return Err(format!(
+            "could not parse {:?} in base {}: misplaced sign",
+            s, radix
+        ));
+    }
+
+    // Put the sign back on, so that `i64::MIN` (whose magnitude doesn't fit in
+    // an `i64`) still parses.
+    let number = format!("{}{}", sign, digits);
+    i64::from_str_radix(&number, radix)
+        .map_err(|e| format!("could not parse {:?} in base {}: {}", s, radix, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decimal() {
+        assert_eq!(parse_int("1234", 10), Ok(1234));
+        assert_eq!(parse_int("-42", 10), Ok(-42));
+        assert_eq!(parse_int("+42", 10), Ok(42));
+    }
+
+    #[test]
+    fn hex() {
+        assert_eq!(parse_int("ff", 16), Ok(255));
+        assert_eq!(parse_int("0xFF", 16), Ok(255));
+        assert_eq!(parse_int("0x7f", 10), Ok(127));
+        assert_eq!(parse_int("-0x8000000000000000", 10), Ok(i64::MIN));
+        assert_eq!(parse_int("+0x10", 10), Ok(16));
+    }
+
+    #[test]
+    fn misplaced_sign() {
+        assert_eq!(
+            parse_int("0x-5", 10),
+            Err(String::from(
+                "could not parse \"0x-5\" in base 16: misplaced sign"
+            ))
+        );
+        assert!(parse_int("-0x-5", 10).is_err());
+        assert!(parse_int("0b+1", 10).is_err());
+        assert!(parse_int("--5", 10).is_err());
+        assert!(parse_int("+-5", 10).is_err());
+    }
+
+    #[test]
+    fn binary() {
+        assert_eq!(parse_int("1011", 2), Ok(11));
+        assert_eq!(parse_int("0b1011", 10), Ok(11));
+        assert_eq!(parse_int("-0B11", 10), Ok(-3));
+    }
+
+    #[test]
+    fn other_radixes() {
+        assert_eq!(parse_int("777", 8), Ok(511));
+        assert_eq!(parse_int("zz", 36), Ok(35 * 36 + 35));
+    }
+
+    #[test]
+    fn invalid_digit() {
+        assert_eq!(
+            parse_int("12a", 10),
+            Err(String::from(
+                "could not parse \"12a\" in base 10: invalid digit found in string"
+            ))
+        );
+        assert!(parse_int("0b102", 10).is_err());
+        assert!(parse_int("0x", 10).is_err());
+    }
+
+    #[test]
+    fn invalid_radix() {
+        assert_eq!(
+            parse_int("1", 1),
+            Err(String::from("radix must be between 2 and 36 (got 1)"))
+        );
+        assert!(parse_int("1", 37).is_err());
+    }
+
+    #[test]
+    fn prefixes() {
+        assert_eq!(split_radix_prefix("0x1f"), (Some(16), "1f"));
+        assert_eq!(split_radix_prefix("0B01"), (Some(2), "01"));
+        assert_eq!(split_radix_prefix("0o17"), (None, "0o17"));
+        assert_eq!(split_radix_prefix("7"), (None, "7"));
+        assert_eq!(split_radix_prefix("é"), (None, "é"));
+    }
+}
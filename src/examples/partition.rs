@@ -0,0 +1,41 @@
+//! Splitting a collection in two with `partition`.
+
+/// Split `data` into its even and odd elements, keeping their order.
+///
+/// `partition` walks the iterator once, putting each element in the first
+/// collection if the predicate is true and the second if it's false. Like
+/// `collect`, it can build any collection type which implements `Default` and
+/// `Extend`, so it needs to be told which one---here the return type does it,
+/// but in a `let`, it would take an annotation like
+/// `let (even, odd): (Vec<i32>, Vec<i32>) = ...` to compile.
+pub fn split_even_odd(data: &[i32]) -> (Vec<i32>, Vec<i32>) {
+    data.iter().partition(|&&x| x % 2 == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_even() {
+        assert_eq!(split_even_odd(&[4, 0, -2]), (vec![4, 0, -2], vec![]));
+    }
+
+    #[test]
+    fn all_odd() {
+        assert_eq!(split_even_odd(&[1, -3, 5]), (vec![], vec![1, -3, 5]));
+    }
+
+    #[test]
+    fn mixed() {
+        assert_eq!(
+            split_even_odd(&[1, 2, 3, 4, 5, 6, -7]),
+            (vec![2, 4, 6], vec![1, 3, 5, -7])
+        );
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(split_even_odd(&[]), (vec![], vec![]));
+    }
+}
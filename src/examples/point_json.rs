@@ -0,0 +1,155 @@
+//! Serialization by hand: a tiny JSON format for one struct.
+//!
+//! In a real project you'd reach for [Serde] (see the [Crates You Should Know
+//! episode][cysk]), but it's worth seeing what it does on your behalf at least
+//! once.
+//!
+//! [Serde]: https://serde.rs
+//! [cysk]: /show_notes/cysk/serde/index.html
+
+/// A point on a plane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Serialize a `Point` as `{"x":1,"y":2}`.
+pub fn to_json(p: &Point) -> String {
+    format!(r#"{{"x":{},"y":{}}}"#, p.x, p.y)
+}
+
+/// Deserialize a `Point` from the format `to_json` produces.
+///
+/// This is deliberately minimal: it accepts whitespace around the tokens and
+/// the fields in either order, but nothing fancier---no nesting, no escape
+/// sequences in the keys, no extra fields. It's strict about what it does
+/// accept, though: an empty field (as in `{"x":1,,"y":2}`, or a trailing
+/// comma) or a key which appears twice is an error, rather than being quietly
+/// skipped or overwritten.
+pub fn from_json(s: &str) -> Result<Point, String> {
+    let body = s
+        .trim()
+        .strip_prefix('{')
+        .and_then(|rest| rest.strip_suffix('}'))
+        .ok_or_else(|| format!("expected an object, got {:?}", s))?;
+
+    let mut x = None;
+    let mut y = None;
+    // An empty object has no fields at all, rather than one empty one; it'll
+    // be reported as missing `"x"` below.
+    let fields = if body.trim().is_empty() {
+        None
+    } else {
+        Some(body.split(','))
+    };
+    for field in fields.into_iter().flatten() {
+        if field.trim().is_empty() {
+            return Err(format!("empty field in {:?}", s));
+        }
+        let (key, value) = field
+            .split_once(':')
+            .ok_or_else(|| format!("expected `\"key\": value`, got {:?}", field))?;
+        let value: i32 = value
+            .trim()
+            .parse()
+            .map_err(|err| format!("bad number {:?}: {}", value.trim(), err))?;
+
+        let slot = match key.trim() {
+            r#""x""# => &mut x,
+            r#""y""# => &mut y,
+            other => return Err(format!("unexpected field {}", other)),
+        };
+        if slot.replace(value).is_some() {
+            return Err(format!("duplicate field {}", key.trim()));
+        }
+    }
+
+    Ok(Point {
+        x: x.ok_or("missing field \"x\"")?,
+        y: y.ok_or("missing field \"y\"")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes() {
+        assert_eq!(to_json(&Point { x: 1, y: 2 }), r#"{"x":1,"y":2}"#);
+        assert_eq!(to_json(&Point { x: -10, y: 0 }), r#"{"x":-10,"y":0}"#);
+    }
+
+    #[test]
+    fn round_trips() {
+        for &p in &[
+            Point { x: 1, y: 2 },
+            Point { x: -7, y: 99 },
+            Point {
+                x: i32::MIN,
+                y: i32::MAX,
+            },
+        ] {
+            assert_eq!(from_json(&to_json(&p)), Ok(p));
+        }
+    }
+
+    #[test]
+    fn tolerates_whitespace_and_order() {
+        assert_eq!(
+            from_json(r#" { "y" : 2 , "x" : 1 } "#),
+            Ok(Point { x: 1, y: 2 })
+        );
+    }
+
+    #[test]
+    fn missing_field() {
+        assert_eq!(
+            from_json(r#"{"x":1}"#),
+            Err(String::from("missing field \"y\""))
+        );
+    }
+
+    #[test]
+    fn empty_object() {
+        assert_eq!(from_json("{ }"), Err(String::from("missing field \"x\"")));
+    }
+
+    #[test]
+    fn empty_field() {
+        assert_eq!(
+            from_json(r#"{"x":1,,"y":2}"#),
+            Err(String::from(r#"empty field in "{\"x\":1,,\"y\":2}""#))
+        );
+        assert!(from_json(r#"{,"x":1,"y":2}"#).is_err());
+    }
+
+    #[test]
+    fn trailing_comma() {
+        let err = from_json(r#"{"x":1,"y":2,}"#).unwrap_err();
+        assert!(err.starts_with("empty field"), "{}", err);
+    }
+
+    #[test]
+    fn duplicate_key() {
+        assert_eq!(
+            from_json(r#"{"x":1,"x":5,"y":2}"#),
+            Err(String::from("duplicate field \"x\""))
+        );
+    }
+
+    #[test]
+    fn bad_number() {
+        let err = from_json(r#"{"x":one,"y":2}"#).unwrap_err();
+        assert!(err.starts_with("bad number \"one\""), "{}", err);
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(from_json("").is_err());
+        assert!(from_json(r#""x":1,"y":2"#).is_err());
+        assert!(from_json(r#"{"x" 1,"y":2}"#).is_err());
+        assert!(from_json(r#"{"x":1,"y":2,"z":3}"#).is_err());
+    }
+}
@@ -0,0 +1,74 @@
+//! `unsafe` code behind a safe wrapper: swapping through raw pointers.
+//!
+//! See [e027] for what `unsafe` does (and doesn't!) mean in Rust.
+//!
+//! [e027]: /show_notes/e027/index.html
+
+use std::ptr;
+
+/// Swap the values behind two raw pointers.
+///
+/// # Safety
+///
+/// The caller must guarantee that:
+///
+///   - both `a` and `b` are non-null, and properly aligned for `T`;
+///   - both point to valid, initialized values of type `T`, which nothing
+///     else is reading or writing for the duration of the call;
+///   - the two values do not overlap. (Swapping a value with *itself* is
+///     overlap, too, and isn't allowed here.)
+///
+/// Break any of those, and the behavior is undefined.
+pub unsafe fn raw_swap<T>(a: *mut T, b: *mut T) {
+    // Bitwise-copy `a` out. For a moment there are two copies of the value,
+    // which is why nothing can be allowed to use or drop `*a` until we've
+    // overwritten it...
+    let tmp = ptr::read(a);
+    // ...which we do right away, with the contents of `b`. This is the step
+    // which requires the values not to overlap.
+    ptr::copy_nonoverlapping(b, a, 1);
+    // Finally, `b` gets the old value of `a`. `ptr::write` doesn't drop the
+    // old contents of `*b`---correctly, since they now live in `*a`.
+    ptr::write(b, tmp);
+}
+
+/// Swap two values, safely.
+///
+/// Two `&mut T`s are guaranteed by the borrow checker to be non-null,
+/// aligned, valid, and---since you can't have two mutable references to the
+/// same thing---non-overlapping. That's every invariant `raw_swap` needs, so
+/// calling it here is sound, and the `unsafe` stays contained in this
+/// function rather than leaking out to every caller.
+pub fn swap<T>(a: &mut T, b: &mut T) {
+    unsafe { raw_swap(a, b) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn swaps_integers() {
+        let mut a = 1;
+        let mut b = 2;
+        swap(&mut a, &mut b);
+        assert_eq!((a, b), (2, 1));
+    }
+
+    #[test]
+    fn swaps_strings() {
+        let mut a = String::from("hello");
+        let mut b = String::from("world");
+        swap(&mut a, &mut b);
+        assert_eq!(a, "world");
+        assert_eq!(b, "hello");
+    }
+
+    #[test]
+    fn swaps_vec_elements() {
+        let mut v = vec![1, 2, 3];
+        let (left, right) = v.split_at_mut(1);
+        swap(&mut left[0], &mut right[1]);
+        assert_eq!(v, vec![3, 2, 1]);
+    }
+}
@@ -0,0 +1,73 @@
+//! Building a `String` with `write!` and `std::fmt::Write`.
+//!
+//! `format!` always allocates a brand new `String`, so building a long string
+//! with `s.push_str(&format!(...))` allocates (and then throws away) a
+//! temporary for every piece. `String` implements `fmt::Write`, though, which
+//! means `write!` and `writeln!` can format straight into the end of an
+//! existing string---no temporaries, and only the occasional reallocation as
+//! the string grows (or none, if it was created `with_capacity`). Note that
+//! it's `std::fmt::Write`, not `std::io::Write`, which is for bytes; the trait
+//! has to be in scope for the macros to find its methods.
+
+use std::fmt::Write;
+
+/// Format `rows` as a table of names and values, with a total at the bottom.
+pub fn build_report(rows: &[(&str, i32)]) -> String {
+    // The padding in `{:<width$}` counts `char`s, not bytes, so the width has
+    // to as well: `"José".len()` is 5.
+    let width = rows
+        .iter()
+        .map(|(name, _)| name.chars().count())
+        .fold("Total".len(), usize::max);
+
+    // Writing to a `String` can't actually fail, so unwrapping here is fine;
+    // the `Result` is only there because `fmt::Write` covers writers which can.
+    let mut report = String::new();
+    for &(name, value) in rows {
+        writeln!(report, "{:<width$}  {:>6}", name, value, width = width).unwrap();
+    }
+    // Summed as an `i64`, which can't overflow: it would take more than four
+    // billion rows of `i32::MAX` to get there.
+    let total: i64 = rows.iter().map(|&(_, value)| i64::from(value)).sum();
+    write!(report, "{:<width$}  {:>6}", "Total", total, width = width).unwrap();
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table() {
+        let report = build_report(&[("apples", 3), ("bananas", 12), ("kiwi", -4)]);
+        let expected = "\
+apples        3
+bananas      12
+kiwi         -4
+Total        11";
+        assert_eq!(report, expected);
+    }
+
+    #[test]
+    fn no_rows() {
+        assert_eq!(build_report(&[]), "Total       0");
+    }
+
+    #[test]
+    fn non_ascii_names() {
+        // "Björn-Åke" is 9 chars but 11 bytes; measuring in bytes would push
+        // every value two columns to the right.
+        let report = build_report(&[("José", 7), ("Björn-Åke", 30)]);
+        let expected = "\
+José            7
+Björn-Åke      30
+Total          37";
+        assert_eq!(report, expected);
+    }
+
+    #[test]
+    fn total_beyond_i32() {
+        let report = build_report(&[("a", i32::MAX), ("b", i32::MAX)]);
+        assert!(report.ends_with("Total  4294967294"), "{}", report);
+    }
+}
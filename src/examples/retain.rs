@@ -0,0 +1,41 @@
+//! Filtering a vector in place with `retain`.
+
+/// Remove every element of `data` which isn't positive.
+///
+/// `retain` keeps the elements for which the closure returns `true`, shifting
+/// them down over the gaps left by the others, so the survivors stay in their
+/// original order. It all happens in place: the vector keeps its existing
+/// allocation (and capacity). The iterator version,
+/// `data.into_iter().filter(|&x| x > 0).collect()`, gives the same elements,
+/// but needs to own the vector and builds the result in a brand new one.
+pub fn keep_positive(data: &mut Vec<i32>) {
+    data.retain(|&x| x > 0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_removed() {
+        let mut data = vec![0, -1, -20];
+        keep_positive(&mut data);
+        assert_eq!(data, vec![]);
+    }
+
+    #[test]
+    fn none_removed() {
+        let mut data = vec![3, 1, 2];
+        keep_positive(&mut data);
+        assert_eq!(data, vec![3, 1, 2]);
+    }
+
+    #[test]
+    fn mixed() {
+        let mut data = vec![5, -1, 0, 8, -3, 2];
+        let capacity = data.capacity();
+        keep_positive(&mut data);
+        assert_eq!(data, vec![5, 8, 2]);
+        assert_eq!(data.capacity(), capacity);
+    }
+}
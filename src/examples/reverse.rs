@@ -0,0 +1,84 @@
+//! Flipping an ordering with `std::cmp::Reverse`.
+//!
+//! `Reverse(x)` is a wrapper whose `Ord` implementation is `x`'s, backward:
+//! `Reverse(1) > Reverse(2)`. So anywhere the standard library uses an
+//! ordering---sorting by a key, a `BinaryHeap`, `max_by_key`---wrapping the
+//! values in `Reverse` turns it around, with no need to write a custom
+//! comparator (and risk getting it the wrong way around).
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Sort `data` from largest to smallest.
+///
+/// `sort_by_key(|&x| Reverse(x))` reads as "sort by the reversed value". The
+/// alternative is `sort_by(|a, b| b.cmp(a))`, where the only thing saying
+/// "descending" is the easily missed order of `a` and `b`.
+#[allow(clippy::ptr_arg)]
+pub fn sort_descending(data: &mut Vec<i32>) {
+    data.sort_by_key(|&x| Reverse(x));
+}
+
+/// The name of the item with the *lowest* priority number, or `None` if there
+/// are no items.
+///
+/// A `BinaryHeap` is a max-heap, so we push `Reverse`d tuples to get a
+/// min-heap, the same trick as the [`top_k`] example. Tuples compare
+/// element by element, so if two items have the same priority, the one whose
+/// name comes first alphabetically wins. (Here, building a whole heap just to
+/// pop one item is overkill, of course---`iter().min()` would do; a heap pays
+/// off when items keep being added and removed.)
+///
+/// The lifetime annotation is needed because there are two input lifetimes
+/// (the slice's, and its `&str`s'), and the result borrows from the strings,
+/// not from the slice; see the [`elision`] example.
+///
+/// [`top_k`]: ../top_k/index.html
+/// [`elision`]: ../elision/index.html
+pub fn min_by_priority<'a>(items: &[(u32, &'a str)]) -> Option<&'a str> {
+    let mut heap: BinaryHeap<Reverse<(u32, &str)>> = items.iter().cloned().map(Reverse).collect();
+    heap.pop().map(|Reverse((_, name))| name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorts_descending() {
+        let mut data = vec![3, -1, 4, 1, 5, 9, 2, 6];
+        sort_descending(&mut data);
+        assert_eq!(data, vec![9, 6, 5, 4, 3, 2, 1, -1]);
+    }
+
+    #[test]
+    fn sorts_with_ties() {
+        let mut data = vec![2, 7, 2, 7, 1];
+        sort_descending(&mut data);
+        assert_eq!(data, vec![7, 7, 2, 2, 1]);
+    }
+
+    #[test]
+    fn sorts_nothing() {
+        let mut data: Vec<i32> = vec![];
+        sort_descending(&mut data);
+        assert_eq!(data, vec![]);
+    }
+
+    #[test]
+    fn lowest_priority() {
+        let items = [(3, "laundry"), (1, "taxes"), (2, "dishes")];
+        assert_eq!(min_by_priority(&items), Some("taxes"));
+    }
+
+    #[test]
+    fn priority_ties() {
+        let items = [(2, "walk dog"), (1, "water plants"), (1, "feed cat")];
+        assert_eq!(min_by_priority(&items), Some("feed cat"));
+    }
+
+    #[test]
+    fn no_items() {
+        assert_eq!(min_by_priority(&[]), None);
+    }
+}
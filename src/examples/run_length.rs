@@ -0,0 +1,63 @@
+//! Lookahead with `Peekable`.
+//!
+//! Calling `.peekable()` on any iterator wraps it in a `Peekable`, which adds
+//! a `peek` method: it returns a reference to the *next* element without
+//! consuming it. (It does have to pull that element out of the underlying
+//! iterator, so `Peekable` stores it until the following `next` call.) That
+//! one element of lookahead is exactly what a lot of simple parsing needs:
+//! "keep going while the next thing looks like this".
+
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Run-length encode `s`: each character, with how many times in a row it
+/// appears.
+pub fn run_length_encode(s: &str) -> Vec<(char, usize)> {
+    let mut chars: Peekable<Chars> = s.chars().peekable();
+    let mut runs = Vec::new();
+    while let Some(c) = chars.next() {
+        let mut count = 1;
+        // Only consume the next character if it continues the run; otherwise
+        // leave it for the outer loop to start a new run with.
+        while chars.peek() == Some(&c) {
+            chars.next();
+            count += 1;
+        }
+        runs.push((c, count));
+    }
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(run_length_encode(""), vec![]);
+    }
+
+    #[test]
+    fn all_distinct() {
+        assert_eq!(run_length_encode("abc"), vec![('a', 1), ('b', 1), ('c', 1)]);
+    }
+
+    #[test]
+    fn long_run() {
+        let s = "z".repeat(1000);
+        assert_eq!(run_length_encode(&s), vec![('z', 1000)]);
+    }
+
+    #[test]
+    fn mixed() {
+        assert_eq!(
+            run_length_encode("aaabccddddé"),
+            vec![('a', 3), ('b', 1), ('c', 2), ('d', 4), ('é', 1)]
+        );
+        // A character can come back after a different one; it's a new run.
+        assert_eq!(
+            run_length_encode("aabaa"),
+            vec![('a', 2), ('b', 1), ('a', 2)]
+        );
+    }
+}
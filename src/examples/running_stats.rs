@@ -0,0 +1,92 @@
+//! Computing several statistics in one pass with `Iterator::fold`.
+//!
+//! We *could* write `data.iter().sum()`, then `data.iter().fold(f64::MIN,
+//! f64::max)`, and so on: one tidy line per statistic. But each of those walks
+//! the whole slice again. That hardly matters for a small slice in memory, but
+//! it's a real cost for a big one---and if the data comes from an iterator you
+//! can only consume *once* (lines being read from a file, say), it's not even
+//! possible. `fold` lets us carry all the running values along together, and
+//! update them all on a single trip through the data.
+
+/// Summary statistics for a series of numbers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub count: usize,
+    pub sum: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+impl Stats {
+    /// The starting point for the fold.
+    ///
+    /// These are the *identity* values for each statistic: combining them
+    /// with any number gives you that number back. Zero for `count` and `sum`,
+    /// and, perhaps less obviously, positive infinity for `min` (every number
+    /// is smaller) and negative infinity for `max`. So for empty input, these
+    /// are exactly what we return.
+    pub fn empty() -> Stats {
+        Stats {
+            count: 0,
+            sum: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// The mean, if there's any data to take the mean of.
+    pub fn mean(&self) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.sum / self.count as f64)
+        }
+    }
+}
+
+/// Count, sum, and find the minimum and maximum of `data`, in one pass.
+pub fn running_stats(data: &[f64]) -> Stats {
+    data.iter().fold(Stats::empty(), |stats, &x| Stats {
+        count: stats.count + 1,
+        sum: stats.sum + x,
+        min: stats.min.min(x),
+        max: stats.max.max(x),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let stats = running_stats(&[]);
+        assert_eq!(stats, Stats::empty());
+        assert_eq!(stats.mean(), None);
+    }
+
+    #[test]
+    fn single_element() {
+        let stats = running_stats(&[4.5]);
+        assert_eq!(
+            stats,
+            Stats {
+                count: 1,
+                sum: 4.5,
+                min: 4.5,
+                max: 4.5,
+            }
+        );
+        assert_eq!(stats.mean(), Some(4.5));
+    }
+
+    #[test]
+    fn negative_only() {
+        let stats = running_stats(&[-3.0, -1.0, -8.0]);
+        assert_eq!(stats.count, 3);
+        assert_eq!(stats.sum, -12.0);
+        assert_eq!(stats.min, -8.0);
+        assert_eq!(stats.max, -1.0);
+        assert_eq!(stats.mean(), Some(-4.0));
+    }
+}
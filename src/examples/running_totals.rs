@@ -0,0 +1,45 @@
+//! Carrying state through an iterator with `scan`.
+//!
+//! `fold` and `scan` both thread an accumulator through each element. The
+//! difference is what comes out: `fold` consumes the whole iterator and
+//! returns only the final accumulator, while `scan` is an adapter, yielding
+//! something at *every* step. Its closure gets a `&mut` to the state, which it
+//! can update however it likes, and returns an `Option`: `Some` to yield a
+//! value, or `None` to end the iteration early.
+
+/// The cumulative sums of `data`: each element is the total of everything up
+/// to and including that point.
+///
+/// The last element is what `data.iter().fold(0, |total, x| total + x)` would
+/// give us.
+pub fn running_totals(data: &[i32]) -> Vec<i32> {
+    data.iter()
+        .scan(0, |total, &x| {
+            *total += x;
+            Some(*total)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(running_totals(&[]), vec![]);
+    }
+
+    #[test]
+    fn single() {
+        assert_eq!(running_totals(&[7]), vec![7]);
+    }
+
+    #[test]
+    fn mixed_signs() {
+        let data = [3, -5, 10, -1, 0, 2];
+        let totals = running_totals(&data);
+        assert_eq!(totals, vec![3, -2, 8, 7, 7, 9]);
+        assert_eq!(totals.last(), Some(&data.iter().sum()));
+    }
+}
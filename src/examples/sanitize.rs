@@ -0,0 +1,54 @@
+//! Allocating only when you have to, with `Cow<str>`.
+//!
+//! `Cow` ("clone on write") is an enum which holds *either* a borrowed value
+//! (`Cow::Borrowed(&str)`) *or* an owned one (`Cow::Owned(String)`). Either
+//! way, it derefs to `&str`, so callers can mostly ignore which one they got.
+//! That lets a function return its input untouched in the common case, and
+//! only pay for a new `String` in the rare case where it has to change
+//! something.
+
+use std::borrow::Cow;
+
+/// Replace any control characters in `input` with spaces.
+///
+/// Note that this counts tabs and newlines as control characters, too.
+pub fn sanitize(input: &str) -> Cow<'_, str> {
+    if !input.chars().any(char::is_control) {
+        return Cow::Borrowed(input);
+    }
+
+    let cleaned = input
+        .chars()
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .collect();
+    Cow::Owned(cleaned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clean_input_is_borrowed() {
+        let result = sanitize("nothing to see here");
+        assert!(matches!(result, Cow::Borrowed(_)));
+        assert_eq!(result, "nothing to see here");
+    }
+
+    #[test]
+    fn empty_input_is_borrowed() {
+        assert!(matches!(sanitize(""), Cow::Borrowed("")));
+    }
+
+    #[test]
+    fn dirty_input_is_owned() {
+        let result = sanitize("bell\u{7}\ttab\u{0}null");
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!(result, "bell  tab null");
+    }
+
+    #[test]
+    fn unicode_is_left_alone() {
+        assert!(matches!(sanitize("héllo 🦀"), Cow::Borrowed(_)));
+    }
+}
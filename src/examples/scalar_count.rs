@@ -0,0 +1,52 @@
+//! Unicode scalar values are not (always) characters.
+//!
+//! A Rust `char` is a *Unicode scalar value*: a single code point. What a
+//! reader thinks of as one character---a *grapheme cluster*, in Unicode's
+//! terms---can be several of those. `"é"` can be written as the single code
+//! point U+00E9, or as a plain `e` followed by U+0301, a combining acute accent
+//! which attaches to whatever comes before it. Both render identically, but
+//! the second is two `char`s. Emoji built with zero-width joiners, and flags
+//! (pairs of "regional indicator" code points) go further still.
+//!
+//! The standard library doesn't do grapheme segmentation, since the rules are
+//! large and change with each Unicode version; the [unicode-segmentation]
+//! crate handles it.
+//!
+//! [unicode-segmentation]: https://crates.io/crates/unicode-segmentation
+
+/// The number of Unicode scalar values in `s`.
+///
+/// This is neither the length in bytes (that's `s.len()`) nor, necessarily,
+/// the number of characters a person would count.
+pub fn count_scalars(s: &str) -> usize {
+    s.chars().count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn precomposed() {
+        let cafe = "caf\u{e9}";
+        assert_eq!(count_scalars(cafe), 4);
+        assert_eq!(cafe.len(), 5);
+    }
+
+    #[test]
+    fn combining_sequence() {
+        // Looks just like the string above, but the `é` is two scalar values.
+        let cafe = "cafe\u{301}";
+        assert_eq!(count_scalars(cafe), 5);
+        assert_eq!(cafe.len(), 6);
+        assert_ne!(cafe, "caf\u{e9}");
+    }
+
+    #[test]
+    fn flag() {
+        // 🇳🇿: one flag, two regional indicators, eight bytes.
+        let flag = "\u{1f1f3}\u{1f1ff}";
+        assert_eq!(count_scalars(flag), 2);
+        assert_eq!(flag.len(), 8);
+    }
+}
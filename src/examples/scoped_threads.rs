@@ -0,0 +1,44 @@
+//! Borrowing stack data across threads with `thread::scope`.
+//!
+//! A thread started with `thread::spawn` might outlive the function which
+//! started it, so it can only capture data it *owns* (hence `move` closures,
+//! and `Arc` for anything shared). `thread::scope` changes the deal: every
+//! thread spawned inside the scope is guaranteed to be joined before `scope`
+//! returns, so those threads can safely borrow local data---no `Arc`, no
+//! `move`, no cloning.
+
+use std::thread;
+
+/// Sum `data` by summing each half on its own thread.
+///
+/// For an odd-length slice, the second half gets the extra element.
+pub fn parallel_sum_scoped(data: &[i32]) -> i32 {
+    let (left, right) = data.split_at(data.len() / 2);
+
+    thread::scope(|s| {
+        let left_sum = s.spawn(|| left.iter().sum::<i32>());
+        let right_sum = s.spawn(|| right.iter().sum::<i32>());
+        left_sum.join().unwrap() + right_sum.join().unwrap()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn even_length() {
+        assert_eq!(parallel_sum_scoped(&[1, 2, 3, 4]), 10);
+    }
+
+    #[test]
+    fn odd_length() {
+        assert_eq!(parallel_sum_scoped(&[1, 2, 3, 4, 5]), 15);
+        assert_eq!(parallel_sum_scoped(&[7]), 7);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(parallel_sum_scoped(&[]), 0);
+    }
+}
@@ -0,0 +1,67 @@
+//! Set operations with `HashSet`.
+
+use std::collections::HashSet;
+
+/// The intersection, union, and difference (`a` minus `b`) of `a` and `b`.
+///
+/// Duplicates within `a` or `b` are ignored, since they collapse when we build
+/// the sets. Iterating over a `HashSet` could produce its elements in any order
+/// at all---and a different one from run to run, since the hasher is randomly
+/// seeded---so we sort the results to make them deterministic (and testable).
+/// If you need a set which stays sorted anyway, `BTreeSet` has the same
+/// methods.
+pub fn set_relations(a: &[i32], b: &[i32]) -> (Vec<i32>, Vec<i32>, Vec<i32>) {
+    let a: HashSet<i32> = a.iter().cloned().collect();
+    let b: HashSet<i32> = b.iter().cloned().collect();
+
+    let mut intersection: Vec<i32> = a.intersection(&b).cloned().collect();
+    let mut union: Vec<i32> = a.union(&b).cloned().collect();
+    let mut difference: Vec<i32> = a.difference(&b).cloned().collect();
+    intersection.sort_unstable();
+    union.sort_unstable();
+    difference.sort_unstable();
+
+    (intersection, union, difference)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overlapping() {
+        let (intersection, union, difference) = set_relations(&[1, 2, 3, 4, 2], &[3, 4, 5]);
+        assert_eq!(intersection, vec![3, 4]);
+        assert_eq!(union, vec![1, 2, 3, 4, 5]);
+        assert_eq!(difference, vec![1, 2]);
+    }
+
+    #[test]
+    fn disjoint() {
+        let (intersection, union, difference) = set_relations(&[5, 1], &[2, 6]);
+        assert_eq!(intersection, vec![]);
+        assert_eq!(union, vec![1, 2, 5, 6]);
+        assert_eq!(difference, vec![1, 5]);
+    }
+
+    #[test]
+    fn identical() {
+        let (intersection, union, difference) = set_relations(&[3, 1, 2], &[2, 3, 1]);
+        assert_eq!(intersection, vec![1, 2, 3]);
+        assert_eq!(union, vec![1, 2, 3]);
+        assert_eq!(difference, vec![]);
+    }
+
+    #[test]
+    fn one_empty() {
+        let (intersection, union, difference) = set_relations(&[7, 8], &[]);
+        assert_eq!(intersection, vec![]);
+        assert_eq!(union, vec![7, 8]);
+        assert_eq!(difference, vec![7, 8]);
+
+        let (intersection, union, difference) = set_relations(&[], &[7, 8]);
+        assert_eq!(intersection, vec![]);
+        assert_eq!(union, vec![7, 8]);
+        assert_eq!(difference, vec![]);
+    }
+}
@@ -0,0 +1,88 @@
+//! Dynamic dispatch with trait objects: `Box<dyn Shape>`.
+//!
+//! There are two ways to write code which works with "anything that's a
+//! `Shape`":
+//!
+//!   - *Static* dispatch, with generics: `fn area_of<S: Shape>(s: &S)`. The
+//!     compiler generates a separate copy of the function for every concrete
+//!     type you use it with ("monomorphization"), so each call is direct, and
+//!     can be inlined. The cost is bigger binaries, and a `Vec<S>` can only
+//!     hold *one* kind of shape.
+//!   - *Dynamic* dispatch, with trait objects: `&dyn Shape` or
+//!     `Box<dyn Shape>`. There's one copy of the function, and each method
+//!     call goes through a table of function pointers (a "vtable") to find the
+//!     right implementation at runtime. That costs an indirection per call,
+//!     and prevents inlining---but it lets us mix different shapes in one
+//!     collection, as `total_area` does.
+
+use std::f64::consts::PI;
+
+/// Anything with an area and a name.
+pub trait Shape {
+    fn area(&self) -> f64;
+    fn name(&self) -> &str;
+}
+
+pub struct Circle {
+    pub radius: f64,
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        PI * self.radius * self.radius
+    }
+
+    fn name(&self) -> &str {
+        "circle"
+    }
+}
+
+pub struct Rectangle {
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Shape for Rectangle {
+    fn area(&self) -> f64 {
+        self.width * self.height
+    }
+
+    fn name(&self) -> &str {
+        "rectangle"
+    }
+}
+
+/// Sum the areas of a collection of (possibly different kinds of) shapes.
+pub fn total_area(shapes: &[Box<dyn Shape>]) -> f64 {
+    shapes.iter().map(|shape| shape.area()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mixed_shapes() {
+        let shapes: Vec<Box<dyn Shape>> = vec![
+            Box::new(Circle { radius: 1.0 }),
+            Box::new(Rectangle {
+                width: 2.0,
+                height: 3.0,
+            }),
+            Box::new(Rectangle {
+                width: 0.5,
+                height: 4.0,
+            }),
+        ];
+
+        assert!((total_area(&shapes) - (PI + 6.0 + 2.0)).abs() < 1e-9);
+
+        let names: Vec<&str> = shapes.iter().map(|shape| shape.name()).collect();
+        assert_eq!(names, vec!["circle", "rectangle", "rectangle"]);
+    }
+
+    #[test]
+    fn no_shapes() {
+        assert_eq!(total_area(&[]), 0.0);
+    }
+}
@@ -0,0 +1,81 @@
+//! Shared, mutable state with `Rc<RefCell<T>>`.
+//!
+//! `Rc` ("reference counted") lets several owners share one value: each
+//! `Rc::clone` bumps a count, each drop decrements it, and the value is freed
+//! when the count reaches zero. But `Rc` only hands out *shared* references,
+//! so on its own we couldn't change the value. `RefCell` fills that gap with
+//! *interior mutability*: it moves the borrow checker's "many readers or one
+//! writer" rule from compile time to run time, so `borrow_mut` works through a
+//! shared reference (and panics if the value is already borrowed).
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// A node in a tree---or, since children can be shared, really a graph.
+#[derive(Debug)]
+pub struct Node {
+    pub value: i32,
+    pub children: Vec<Rc<RefCell<Node>>>,
+}
+
+impl Node {
+    /// Create a new node, already wrapped up for sharing.
+    pub fn new(value: i32) -> Rc<RefCell<Node>> {
+        Rc::new(RefCell::new(Node {
+            value,
+            children: Vec::new(),
+        }))
+    }
+}
+
+/// Sum every value reachable from `root`.
+///
+/// Note that a child shared between two parents gets counted once for each
+/// parent, since we reach it twice.
+pub fn sum_tree(root: &Rc<RefCell<Node>>) -> i32 {
+    let node = root.borrow();
+    node.value + node.children.iter().map(sum_tree).sum::<i32>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sums_a_simple_tree() {
+        let root = Node::new(1);
+        root.borrow_mut().children.push(Node::new(2));
+        root.borrow_mut().children.push(Node::new(3));
+        assert_eq!(sum_tree(&root), 6);
+    }
+
+    #[test]
+    fn shared_child() {
+        let shared = Node::new(10);
+        assert_eq!(Rc::strong_count(&shared), 1);
+
+        let left = Node::new(1);
+        let right = Node::new(2);
+
+        // Cloning an `Rc` doesn't clone the `Node`; it just bumps the count.
+        left.borrow_mut().children.push(Rc::clone(&shared));
+        assert_eq!(Rc::strong_count(&shared), 2);
+        right.borrow_mut().children.push(Rc::clone(&shared));
+        assert_eq!(Rc::strong_count(&shared), 3);
+
+        let root = Node::new(0);
+        root.borrow_mut().children.push(Rc::clone(&left));
+        root.borrow_mut().children.push(Rc::clone(&right));
+        assert_eq!(sum_tree(&root), 23);
+
+        // Change the shared child once, and both parents see it.
+        shared.borrow_mut().value = 100;
+        assert_eq!(sum_tree(&left), 101);
+        assert_eq!(sum_tree(&right), 102);
+
+        // Dropping a parent drops its reference to the child.
+        drop(root);
+        drop(left);
+        assert_eq!(Rc::strong_count(&shared), 2);
+    }
+}
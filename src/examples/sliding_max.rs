@@ -0,0 +1,100 @@
+//! Sliding-window maximum with a `VecDeque`.
+//!
+//! `VecDeque` is a growable ring buffer: pushing and popping at *either* end
+//! is O(1), where a `Vec` is only cheap at the back. That's exactly what the
+//! *monotonic deque* algorithm needs.
+
+use std::collections::VecDeque;
+
+/// The maximum of each window of `k` consecutive elements of `data`.
+///
+/// Returns an empty vector if `k` is 0 or longer than `data`.
+///
+/// The deque holds indices of elements which could still turn out to be the
+/// maximum of some window, with their values decreasing from front to back.
+/// For each new element:
+///
+///   - drop the front index if it has slid out of the window;
+///   - drop indices from the back while their values are no bigger than the
+///     new one---they can never be a maximum again, since the new element is
+///     at least as big and will stay in the window longer;
+///   - push the new index on the back.
+///
+/// The front of the deque is then the index of the current window's maximum.
+/// Each index is pushed once and popped at most once, so the whole thing is
+/// O(n) amortized, however big `k` is, rather than the O(n·k) of checking
+/// every window separately.
+pub fn sliding_max(data: &[i32], k: usize) -> Vec<i32> {
+    if k == 0 || k > data.len() {
+        return Vec::new();
+    }
+
+    let mut candidates: VecDeque<usize> = VecDeque::with_capacity(k);
+    let mut maxima = Vec::with_capacity(data.len() - k + 1);
+    for (i, &value) in data.iter().enumerate() {
+        if matches!(candidates.front(), Some(&front) if front + k <= i) {
+            candidates.pop_front();
+        }
+        while matches!(candidates.back(), Some(&back) if data[back] <= value) {
+            candidates.pop_back();
+        }
+        candidates.push_back(i);
+
+        if i + 1 >= k {
+            maxima.push(data[candidates[0]]);
+        }
+    }
+    maxima
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brute_force(data: &[i32], k: usize) -> Vec<i32> {
+        if k == 0 || k > data.len() {
+            return Vec::new();
+        }
+        data.windows(k)
+            .map(|window| *window.iter().max().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(
+            sliding_max(&[1, 3, -1, -3, 5, 3, 6, 7], 3),
+            vec![3, 3, 5, 5, 6, 7]
+        );
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let inputs: [&[i32]; 5] = [
+            &[4, 2, 12, 11, -5, 7, 7, 0, 3],
+            &[1, 2, 3, 4, 5, 6, 7, 8],
+            &[8, 7, 6, 5, 4, 3, 2, 1],
+            &[5, 5, 5, 5, 5],
+            &[9, -2, 14, 3, 3, -8, 21, 0, 6, 6, 1, -13],
+        ];
+        for data in inputs.iter() {
+            for k in 1..=data.len() {
+                assert_eq!(
+                    sliding_max(data, k),
+                    brute_force(data, k),
+                    "data = {:?}, k = {}",
+                    data,
+                    k
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn degenerate_windows() {
+        assert_eq!(sliding_max(&[1, 2, 3], 0), Vec::<i32>::new());
+        assert_eq!(sliding_max(&[1, 2, 3], 4), Vec::<i32>::new());
+        assert_eq!(sliding_max(&[], 1), Vec::<i32>::new());
+        assert_eq!(sliding_max(&[1, 2, 3], 3), vec![3]);
+    }
+}
@@ -0,0 +1,102 @@
+//! Sorting with `sort_by` and `sort_by_key`, and why stability matters.
+//!
+//! `sort`, `sort_by`, and `sort_by_key` are all *stable*: elements which
+//! compare equal keep the order they started in. The `sort_unstable` family
+//! makes no such promise, and in exchange is usually faster and sorts in place
+//! without allocating. If you don't care about the order of ties (or there
+//! can't be any, as with a list of distinct numbers), the unstable versions are
+//! the better default.
+//!
+//! There's no test here *showing* an unstable sort reordering ties, because
+//! there's no portable way to write one. Whether a given input comes out
+//! reordered depends on the algorithm the standard library happens to use
+//! (which has changed before, and may again); small inputs, in particular,
+//! are often sorted with a method which is stable anyway. "May reorder" is a
+//! lack of a promise, not a behavior we can count on seeing.
+
+/// A person, for sorting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Person {
+    pub name: String,
+    pub age: u8,
+}
+
+impl Person {
+    pub fn new(name: &str, age: u8) -> Person {
+        Person {
+            name: name.to_string(),
+            age,
+        }
+    }
+}
+
+/// Sort by age, youngest first, and then by name for people the same age.
+///
+/// Sorting by a tuple key, `(p.age, p.name.clone())`, would work, but would
+/// clone every name on every comparison. Instead this leans on stability:
+/// sort by the *secondary* key first, then by the primary key. Because the
+/// second sort is stable, people the same age stay in the name order the
+/// first sort put them in.
+#[allow(clippy::ptr_arg)]
+pub fn sort_people(people: &mut Vec<Person>) {
+    people.sort_by(|a, b| a.name.cmp(&b.name));
+    people.sort_by_key(|p| p.age);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(people: &[Person]) -> Vec<&str> {
+        people.iter().map(|p| p.name.as_str()).collect()
+    }
+
+    #[test]
+    fn sorts_by_age_then_name() {
+        let mut people = vec![
+            Person::new("Carol", 40),
+            Person::new("Bob", 30),
+            Person::new("Dave", 30),
+            Person::new("Alice", 30),
+            Person::new("Eve", 20),
+        ];
+        sort_people(&mut people);
+        assert_eq!(names(&people), vec!["Eve", "Alice", "Bob", "Dave", "Carol"]);
+    }
+
+    #[test]
+    fn stable_sort_keeps_ties_in_order() {
+        let mut people = [
+            Person::new("Zed", 30),
+            Person::new("Amy", 20),
+            Person::new("Moe", 30),
+            Person::new("Bea", 30),
+        ];
+        people.sort_by_key(|p| p.age);
+        // The three 30-year-olds are in their original relative order.
+        assert_eq!(names(&people), vec!["Amy", "Zed", "Moe", "Bea"]);
+    }
+
+    #[test]
+    fn unstable_sort_keeps_every_element() {
+        let mut people = [
+            Person::new("Zed", 30),
+            Person::new("Amy", 20),
+            Person::new("Moe", 30),
+            Person::new("Bea", 30),
+        ];
+        people.sort_unstable_by_key(|p| p.age);
+
+        // The ages are sorted...
+        let ages: Vec<u8> = people.iter().map(|p| p.age).collect();
+        assert_eq!(ages, vec![20, 30, 30, 30]);
+
+        // ...and none of the 30-year-olds went missing. They're allowed to
+        // come out in *any* order, so that's all we can check; asserting a
+        // specific order (original or not) would be relying on an
+        // implementation detail.
+        let mut ties = names(&people[1..]);
+        ties.sort_unstable();
+        assert_eq!(ties, vec!["Bea", "Moe", "Zed"]);
+    }
+}
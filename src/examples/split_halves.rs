@@ -0,0 +1,59 @@
+//! Two mutable borrows of one slice, with `split_at_mut`.
+
+use std::mem;
+
+/// Swap the first half of `data` with the second half.
+///
+/// If the length is odd, the middle element stays where it is.
+///
+/// Swapping `data[i]` with `data[j]` by taking `&mut data[i]` and
+/// `&mut data[j]` at the same time won't compile: the borrow checker only sees
+/// two mutable borrows of `data`, and can't tell that `i` and `j` are
+/// different. `split_at_mut` solves that by splitting one `&mut [i32]` into
+/// two which *don't* overlap---one for everything before the split point and
+/// one for everything after---and the compiler is happy to let us use both at
+/// once, because as far as it's concerned, they're borrows of two different
+/// things. (Inside, `split_at_mut` needs `unsafe` to do this; [e027] talks
+/// about building safe interfaces like that one on top of `unsafe` code.)
+///
+/// [e027]: /show_notes/e027/index.html
+pub fn swap_halves(data: &mut [i32]) {
+    let half = data.len() / 2;
+    let (front, back) = data.split_at_mut(half);
+    // Skip the middle element, if there is one.
+    let middle = back.len() - half;
+    let back = &mut back[middle..];
+    for (a, b) in front.iter_mut().zip(back.iter_mut()) {
+        mem::swap(a, b);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn even_length() {
+        let mut data = [1, 2, 3, 4, 5, 6];
+        swap_halves(&mut data);
+        assert_eq!(data, [4, 5, 6, 1, 2, 3]);
+    }
+
+    #[test]
+    fn odd_length() {
+        let mut data = [1, 2, 3, 4, 5];
+        swap_halves(&mut data);
+        assert_eq!(data, [4, 5, 3, 1, 2]);
+    }
+
+    #[test]
+    fn tiny() {
+        let mut empty: [i32; 0] = [];
+        swap_halves(&mut empty);
+        assert_eq!(empty, []);
+
+        let mut one = [7];
+        swap_halves(&mut one);
+        assert_eq!(one, [7]);
+    }
+}
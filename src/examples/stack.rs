@@ -0,0 +1,113 @@
+//! Making a custom collection work in `for` loops with `IntoIterator`.
+//!
+//! A `for` loop works on anything which implements `IntoIterator`: `for x in
+//! thing` is sugar for calling `IntoIterator::into_iter(thing)` and then
+//! `next` until it returns `None`. Collections usually want to support three
+//! ways of looping, and each one is a separate impl, because each one is for
+//! a different *type*:
+//!
+//!   - `for x in stack` consumes the `Stack<T>`, yielding owned `T`s.
+//!   - `for x in &stack` borrows it, yielding `&T`s.
+//!   - `for x in &mut stack` borrows it mutably, yielding `&mut T`s.
+//!
+//! `Stack<T>`, `&Stack<T>`, and `&mut Stack<T>` are three distinct types, so
+//! they each need their own `IntoIterator` impl. Here, each one just hands off
+//! to the matching iterator from the inner `Vec`.
+
+/// A last-in, first-out stack.
+///
+/// Note that iteration goes from the *bottom* of the stack to the top, in
+/// the order things were pushed, since that's how the inner `Vec` iterates.
+#[derive(Debug, Default)]
+pub struct Stack<T> {
+    items: Vec<T>,
+}
+
+impl<T> Stack<T> {
+    pub fn new() -> Stack<T> {
+        Stack { items: Vec::new() }
+    }
+
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+}
+
+impl<T> IntoIterator for Stack<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Stack<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Stack<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stack_of(items: &[&str]) -> Stack<String> {
+        let mut stack = Stack::new();
+        for item in items {
+            stack.push(item.to_string());
+        }
+        stack
+    }
+
+    #[test]
+    fn by_value() {
+        let stack = stack_of(&["a", "b", "c"]);
+        let mut owned: Vec<String> = Vec::new();
+        for item in stack {
+            owned.push(item);
+        }
+        assert_eq!(owned, vec!["a", "b", "c"]);
+        // `stack` has been moved; using it here would be a compile error.
+    }
+
+    #[test]
+    fn by_reference() {
+        let stack = stack_of(&["a", "b", "c"]);
+        let mut total_len = 0;
+        for item in &stack {
+            total_len += item.len();
+        }
+        assert_eq!(total_len, 3);
+        // Still ours:
+        assert_eq!(stack.items.len(), 3);
+    }
+
+    #[test]
+    fn by_mutable_reference() {
+        let mut stack = stack_of(&["a", "b", "c"]);
+        for item in &mut stack {
+            item.push('!');
+        }
+        assert_eq!(stack.pop(), Some(String::from("c!")));
+        assert_eq!(stack.pop(), Some(String::from("b!")));
+        assert_eq!(stack.pop(), Some(String::from("a!")));
+        assert_eq!(stack.pop(), None);
+    }
+}
@@ -0,0 +1,82 @@
+//! Building an iterator adapter, with an associated type.
+//!
+//! (The standard library already has this one as `Iterator::step_by`; the
+//! point here is to see how such adapters are put together.)
+
+/// An iterator which yields every `step`th element of another iterator,
+/// starting with the first.
+pub struct StepBy<I> {
+    iter: I,
+    step: usize,
+}
+
+impl<I: Iterator> StepBy<I> {
+    /// Wrap `iter`, yielding every `step`th element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero, since "every zeroth element" doesn't mean
+    /// anything. We check here, up front, so that the mistake is reported where
+    /// it's made, rather than at some later call to `next`.
+    pub fn new(iter: I, step: usize) -> StepBy<I> {
+        assert!(step != 0, "step must be non-zero");
+        StepBy { iter, step }
+    }
+}
+
+/// The adapter yields whatever the underlying iterator yields, so its `Item`
+/// is defined in terms of *that* iterator's `Item`: `I::Item`. This is the
+/// advantage of an associated type over a generic parameter: there's exactly
+/// one `Item` for any given `I`, so nobody ever has to spell it out.
+impl<I: Iterator> Iterator for StepBy<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let item = self.iter.next()?;
+        // Skip the `step - 1` elements between this one and the next. `nth(n)`
+        // consumes `n + 1` elements, hence `step - 2`.
+        if self.step > 1 {
+            self.iter.nth(self.step - 2);
+        }
+        Some(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stepped(step: usize) -> Vec<i32> {
+        StepBy::new(0..10, step).collect()
+    }
+
+    #[test]
+    fn step_one_yields_everything() {
+        assert_eq!(stepped(1), (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn step_two() {
+        assert_eq!(stepped(2), vec![0, 2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn step_three() {
+        assert_eq!(stepped(3), vec![0, 3, 6, 9]);
+    }
+
+    #[test]
+    fn matches_std() {
+        for step in 1..12 {
+            let ours: Vec<i32> = StepBy::new(0..10, step).collect();
+            let theirs: Vec<i32> = (0..10).step_by(step).collect();
+            assert_eq!(ours, theirs, "step {}", step);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "step must be non-zero")]
+    fn step_zero_panics() {
+        StepBy::new(0..10, 0);
+    }
+}
@@ -0,0 +1,76 @@
+//! Stepping through a range in either direction.
+
+/// The values from `start` up to (but not including) `end`, `step` apart.
+///
+/// A negative `step` counts *down*, from `start` to just above `end`. If
+/// `step` is zero, or points away from `end`, the result is empty---rather
+/// than an infinite loop.
+///
+/// `Iterator::step_by` takes a `usize`, so it can only skip forward through
+/// whatever iterator it's given: there's no such thing as a negative number of
+/// elements to skip. And for ranges, `start..end` with `start > end` is simply
+/// empty rather than a countdown. So to go backward, we build the range the
+/// right way around, `rev` it, and then step through that with the size of the
+/// step. (See the [`step_by`] example for how such an adapter works.)
+///
+/// [`step_by`]: ../step_by/index.html
+pub fn stepped_range(start: i64, end: i64, step: i64) -> Vec<i64> {
+    if step > 0 && start < end {
+        (start..end).step_by(step as usize).collect()
+    } else if step < 0 && start > end {
+        // The same values as `start`, `start - |step|`, ..., staying above
+        // `end`. (`end + 1` can't overflow, since `end < start`.)
+        (end + 1..=start)
+            .rev()
+            .step_by(step.unsigned_abs() as usize)
+            .collect()
+    } else {
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascending() {
+        assert_eq!(stepped_range(0, 10, 3), vec![0, 3, 6, 9]);
+        assert_eq!(stepped_range(-4, 4, 2), vec![-4, -2, 0, 2]);
+    }
+
+    #[test]
+    fn descending() {
+        assert_eq!(stepped_range(10, 0, -3), vec![10, 7, 4, 1]);
+        assert_eq!(stepped_range(5, -5, -5), vec![5, 0]);
+    }
+
+    #[test]
+    fn single_element() {
+        assert_eq!(stepped_range(3, 4, 1), vec![3]);
+        assert_eq!(stepped_range(3, 2, -1), vec![3]);
+        assert_eq!(stepped_range(0, 10, 100), vec![0]);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(stepped_range(0, 10, 0), vec![]);
+        assert_eq!(stepped_range(0, 10, -1), vec![]);
+        assert_eq!(stepped_range(10, 0, 1), vec![]);
+        assert_eq!(stepped_range(5, 5, 1), vec![]);
+        assert_eq!(stepped_range(5, 5, -1), vec![]);
+    }
+
+    #[test]
+    fn extremes() {
+        // A step of 2⁶³ still fits in a (64-bit) `usize`, and nothing overflows.
+        assert_eq!(
+            stepped_range(i64::MAX, i64::MIN, i64::MIN),
+            vec![i64::MAX, -1]
+        );
+        assert_eq!(
+            stepped_range(i64::MIN, i64::MAX, i64::MAX),
+            vec![i64::MIN, -1, i64::MAX - 1]
+        );
+    }
+}
@@ -0,0 +1,81 @@
+//! Reading input line by line with `BufRead`.
+//!
+//! Like `cli_flags`, this is about designing for testability: rather than
+//! reading from `io::stdin()` directly, the function takes any `BufRead`.
+//! A real program passes in `io::stdin().lock()`; a test passes in a
+//! `Cursor` wrapped around some bytes.
+
+use std::io::{self, BufRead};
+
+/// Sum the numbers in `reader`, one per line.
+///
+/// Blank (or all-whitespace) lines are skipped. A line which isn't a valid
+/// `i64` is an error: we convert the `ParseIntError` into an `io::Error` with
+/// kind `InvalidData`, so the function only has one error type to report. A
+/// sum too big for an `i64` is reported the same way: a plain `+=` would
+/// panic in debug builds, and quietly wrap around in release builds.
+pub fn sum_stdin_numbers<R: BufRead>(reader: R) -> io::Result<i64> {
+    let mut sum: i64 = 0;
+    for line in reader.lines() {
+        let line = line?;
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        let n: i64 = trimmed.parse().map_err(|err| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid number {:?}: {}", trimmed, err),
+            )
+        })?;
+        sum = sum.checked_add(n).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("sum overflowed adding {}", n),
+            )
+        })?;
+    }
+
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn sums_lines() {
+        let input = Cursor::new(&b"1\n2\n-3\n10"[..]);
+        assert_eq!(sum_stdin_numbers(input).unwrap(), 10);
+    }
+
+    #[test]
+    fn trailing_newline_and_blank_lines() {
+        let input = Cursor::new(&b"\n5\n\n   \n6\n"[..]);
+        assert_eq!(sum_stdin_numbers(input).unwrap(), 11);
+    }
+
+    #[test]
+    fn empty() {
+        let input = Cursor::new(&b""[..]);
+        assert_eq!(sum_stdin_numbers(input).unwrap(), 0);
+    }
+
+    #[test]
+    fn overflow() {
+        let input = Cursor::new(&b"9223372036854775807\n1\n"[..]);
+        let err = sum_stdin_numbers(input).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(err.to_string(), "sum overflowed adding 1");
+    }
+
+    #[test]
+    fn malformed_line() {
+        let input = Cursor::new(&b"1\ntwo\n3\n"[..]);
+        let err = sum_stdin_numbers(input).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("\"two\""));
+    }
+}
@@ -0,0 +1,72 @@
+//! Supertraits: traits which require other traits.
+//!
+//! `trait Summary: Display` means "to implement `Summary`, a type must also
+//! implement `Display`". That's what lets the default `summarize` below format
+//! `self` with `{}`: inside the trait, every `Self` is known to be `Display`.
+//! It's a requirement, not an inheritance relationship, though: implementing
+//! `Summary` doesn't implement `Display` for you, and forgetting to is an
+//! error---`error[E0277]: ... doesn't implement std::fmt::Display`, pointing at
+//! the `impl Summary`:
+//!
+//! ```compile_fail
+//! use show_notes::examples::supertraits::Summary;
+//!
+//! struct Untitled;
+//! impl Summary for Untitled {}
+//! ```
+
+use std::fmt::{self, Display};
+
+/// Something which can be summarized.
+pub trait Summary: Display {
+    /// A short teaser, which by default uses the `Display` output.
+    fn summarize(&self) -> String {
+        format!("(Read more from {}...)", self)
+    }
+}
+
+/// An episode of a podcast.
+pub struct Episode {
+    pub show: String,
+    pub number: u32,
+}
+
+impl Display for Episode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} #{}", self.show, self.number)
+    }
+}
+
+impl Summary for Episode {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary_uses_display() {
+        let episode = Episode {
+            show: String::from("New Rustacean"),
+            number: 22,
+        };
+        assert_eq!(episode.to_string(), "New Rustacean #22");
+        assert_eq!(episode.summarize(), "(Read more from New Rustacean #22...)");
+    }
+
+    #[test]
+    fn generic_code_gets_display_too() {
+        // A `Summary` bound is enough to use `{}`, thanks to the supertrait.
+        fn headline<S: Summary>(item: &S) -> String {
+            format!("{}: {}", item, item.summarize())
+        }
+
+        let episode = Episode {
+            show: String::from("New Rustacean"),
+            number: 1,
+        };
+        assert_eq!(
+            headline(&episode),
+            "New Rustacean #1: (Read more from New Rustacean #1...)"
+        );
+    }
+}
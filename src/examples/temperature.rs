@@ -0,0 +1,53 @@
+//! Conversions with `From` and `Into`.
+//!
+//! The standard library has a blanket implementation,
+//! `impl<T, U: From<T>> Into<U> for T`, which says: if you can make a `U`
+//! *from* a `T`, then a `T` can be turned *into* a `U`. So you only ever need
+//! to implement `From`; `Into` comes along for free. (That's why the usual
+//! advice is to implement `From`, and to *accept* `Into` in function
+//! signatures.)
+
+/// A temperature in degrees Celsius.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Celsius(pub f64);
+
+/// A temperature in degrees Fahrenheit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fahrenheit(pub f64);
+
+impl From<Celsius> for Fahrenheit {
+    fn from(c: Celsius) -> Fahrenheit {
+        Fahrenheit(c.0 * 9.0 / 5.0 + 32.0)
+    }
+}
+
+impl From<Fahrenheit> for Celsius {
+    fn from(f: Fahrenheit) -> Celsius {
+        Celsius((f.0 - 32.0) * 5.0 / 9.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn freezing() {
+        assert_eq!(Fahrenheit::from(Celsius(0.0)), Fahrenheit(32.0));
+    }
+
+    #[test]
+    fn boiling() {
+        // We never implemented `Into`, but we can use it anyway.
+        let f: Fahrenheit = Celsius(100.0).into();
+        assert_eq!(f, Fahrenheit(212.0));
+    }
+
+    #[test]
+    fn round_trip() {
+        let original = Celsius(37.5);
+        let there: Fahrenheit = original.into();
+        let back: Celsius = there.into();
+        assert!((back.0 - original.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,39 @@
+//! Timing code with `std::time::Instant`.
+//!
+//! [e007] uses `#[bench]`, which (still!) requires nightly Rust. For a quick
+//! measurement on stable, `Instant` does the job: it's a monotonic clock, so
+//! unlike `SystemTime`, it can't jump backward if the system clock is adjusted.
+//! A single timing is much noisier than a benchmark run, though. There's no
+//! warm-up, no repetition, and nothing to stop the optimizer from getting
+//! clever; for serious measurements, a crate like [criterion] works on stable
+//! and handles all of that.
+//!
+//! [e007]: /show_notes/e007/index.html
+//! [criterion]: https://crates.io/crates/criterion
+
+use std::time::{Duration, Instant};
+
+/// Run `f` once, returning its result along with how long it took.
+pub fn time_it<F: FnOnce() -> T, T>(f: F) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread::sleep;
+
+    #[test]
+    fn measures_at_least_the_sleep() {
+        let ((), elapsed) = time_it(|| sleep(Duration::from_millis(5)));
+        assert!(elapsed >= Duration::from_millis(5), "{:?}", elapsed);
+    }
+
+    #[test]
+    fn returns_the_result() {
+        let (sum, _) = time_it(|| (1..=100).sum::<u32>());
+        assert_eq!(sum, 5050);
+    }
+}
@@ -0,0 +1,80 @@
+//! Top-k selection with a `BinaryHeap`.
+//!
+//! `std::collections::BinaryHeap` is a priority queue: `push` and `pop` are
+//! O(log n), and `pop` always returns the *largest* element. Wrapping the
+//! elements in `std::cmp::Reverse` flips the ordering, making it a min-heap.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// The `k` largest elements of `data`, largest first.
+///
+/// If `k` is at least the length of `data`, that's all of `data`, sorted.
+///
+/// We keep a min-heap of the `k` biggest elements seen so far. Its top is the
+/// smallest of them, so each new element only has to beat that one to get in,
+/// at which point the top gets popped off to make room. That's O(n log k)
+/// time and O(k) extra space. A full sort is O(n log n), which is no worse
+/// when `k` is close to `n`, but the heap wins when `k` is small and `n` is
+/// large---especially if `data` is streaming in and can't all be kept around
+/// to sort.
+pub fn top_k(data: &[i32], k: usize) -> Vec<i32> {
+    if k == 0 {
+        return Vec::new();
+    }
+
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+    for &value in data {
+        heap.push(Reverse(value));
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+
+    // Ascending order of `Reverse` is descending order of the values.
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(value)| value)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn by_sorting(data: &[i32], k: usize) -> Vec<i32> {
+        let mut sorted = data.to_vec();
+        sorted.sort_unstable_by(|a, b| b.cmp(a));
+        sorted.truncate(k);
+        sorted
+    }
+
+    #[test]
+    fn matches_sorting() {
+        let inputs: [&[i32]; 4] = [
+            &[5, 1, 9, -3, 9, 12, 0, 4, 7],
+            &[1, 2, 3, 4, 5, 6],
+            &[3, 3, 3, 1, 1],
+            &[-7, -2, -19, -4],
+        ];
+        for data in inputs.iter() {
+            for k in 0..=data.len() + 1 {
+                assert_eq!(
+                    top_k(data, k),
+                    by_sorting(data, k),
+                    "data = {:?}, k = {}",
+                    data,
+                    k
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn edge_cases() {
+        assert_eq!(top_k(&[4, 8, 2], 0), Vec::<i32>::new());
+        assert_eq!(top_k(&[4, 8, 2], 3), vec![8, 4, 2]);
+        assert_eq!(top_k(&[4, 8, 2], 10), vec![8, 4, 2]);
+        assert_eq!(top_k(&[], 2), Vec::<i32>::new());
+    }
+}
@@ -0,0 +1,58 @@
+//! A tiny state machine with `enum` and `match`.
+//!
+//! See [e003] for more on enums and pattern matching.
+//!
+//! [e003]: /show_notes/e003/index.html
+
+/// The states a traffic light can be in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Light {
+    Red,
+    Yellow,
+    Green,
+}
+
+/// Get the state which follows `light`.
+///
+/// Note there's no `_ =>` catch-all arm. A `match` has to be *exhaustive*, so
+/// if we ever added a variant to `Light`---say, `FlashingRed`---this function
+/// would stop compiling until we decided what comes after it. A wildcard arm
+/// would quietly swallow the new variant instead, which is why it's worth
+/// avoiding them when matching on your own enums.
+pub fn next(light: Light) -> Light {
+    match light {
+        Light::Red => Light::Green,
+        Light::Green => Light::Yellow,
+        Light::Yellow => Light::Red,
+    }
+}
+
+/// Advance `steps` times from `start`.
+pub fn cycle(start: Light, steps: usize) -> Light {
+    (0..steps).fold(start, |light, _| next(light))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_cycle() {
+        assert_eq!(next(Light::Red), Light::Green);
+        assert_eq!(next(Light::Green), Light::Yellow);
+        assert_eq!(next(Light::Yellow), Light::Red);
+    }
+
+    #[test]
+    fn three_steps_returns_to_start() {
+        for &light in &[Light::Red, Light::Yellow, Light::Green] {
+            assert_eq!(cycle(light, 3), light);
+        }
+    }
+
+    #[test]
+    fn zero_and_many_steps() {
+        assert_eq!(cycle(Light::Red, 0), Light::Red);
+        assert_eq!(cycle(Light::Red, 3001), Light::Green);
+    }
+}
@@ -0,0 +1,61 @@
+//! Newtype IDs: keeping different kinds of identifier apart.
+//!
+//! If users and products are both identified by a bare `u32`, nothing stops a
+//! product ID from being passed where a user ID was meant; it'll type-check,
+//! and just quietly look up the wrong thing. Wrapping each kind of ID in its
+//! own single-field struct (the newtype pattern, from [e021]) makes them
+//! different types, so that mix-up becomes a compile error:
+//!
+//! ```compile_fail
+//! use std::collections::HashMap;
+//! use show_notes::examples::typed_ids::{lookup_user, ProductId, UserId};
+//!
+//! let store: HashMap<UserId, String> = HashMap::new();
+//! lookup_user(&store, ProductId(7)); // error[E0308]: mismatched types
+//! ```
+//!
+//! A newtype is exactly the same size as the value it wraps, so the safety is
+//! free at runtime. Deriving `Hash`, `Eq`, and friends gives each ID type just
+//! the operations an ID needs---notably, *not* arithmetic, which rarely makes
+//! sense for an identifier.
+//!
+//! [e021]: /show_notes/e021/index.html
+
+use std::collections::HashMap;
+
+/// Identifies a user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UserId(pub u32);
+
+/// Identifies a product.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ProductId(pub u32);
+
+/// Get the name of the user with the given `id`.
+pub fn lookup_user(store: &HashMap<UserId, String>, id: UserId) -> Option<&String> {
+    store.get(&id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup() {
+        let mut store = HashMap::new();
+        store.insert(UserId(1), String::from("ferris"));
+        store.insert(UserId(2), String::from("corro"));
+
+        assert_eq!(lookup_user(&store, UserId(2)), Some(&String::from("corro")));
+        assert_eq!(lookup_user(&store, UserId(3)), None);
+    }
+
+    #[test]
+    fn same_number_different_ids() {
+        let user = UserId(7);
+        let product = ProductId(7);
+        // The underlying numbers are equal, but comparing `user == product`
+        // wouldn't compile; we have to unwrap them and compare deliberately.
+        assert_eq!(user.0, product.0);
+    }
+}
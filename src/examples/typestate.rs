@@ -0,0 +1,108 @@
+//! The typestate pattern: making invalid state transitions a compile error.
+//!
+//! Rather than storing a connection's state in a field and checking it at
+//! runtime, we make the state part of the *type*: a `Connection<Disconnected>`
+//! and a `Connection<Connected>` are different types, with different methods.
+//! `send` only exists on the connected one, so sending before connecting
+//! doesn't panic or return an error---it doesn't compile:
+//!
+//! ```compile_fail
+//! use show_notes::examples::typestate::Connection;
+//!
+//! let connection = Connection::new("example.com:80");
+//! connection.send("hello"); // error[E0599]: no method named `send` found
+//! ```
+//!
+//! Moving between states consumes the old value (`connect` takes `self`), so
+//! there's no stale `Connection<Disconnected>` left lying around to misuse
+//! either. And since the state markers are zero-sized, tracked with
+//! `PhantomData` as in the [`units`] example, all of this disappears at
+//! compile time: at runtime, `Connection` is just its address.
+//!
+//! [`units`]: ../units/index.html
+
+use std::marker::PhantomData;
+
+/// Marker type for a connection which hasn't been opened yet.
+#[derive(Debug)]
+pub struct Disconnected;
+
+/// Marker type for an open connection.
+#[derive(Debug)]
+pub struct Connected;
+
+/// A (pretend) network connection, in one of the states above.
+#[derive(Debug)]
+pub struct Connection<State> {
+    address: String,
+    _state: PhantomData<State>,
+}
+
+impl Connection<Disconnected> {
+    /// Prepare a connection to `address`, without opening it yet.
+    pub fn new(address: &str) -> Connection<Disconnected> {
+        Connection {
+            address: address.to_string(),
+            _state: PhantomData,
+        }
+    }
+
+    /// Open the connection.
+    pub fn connect(self) -> Connection<Connected> {
+        Connection {
+            address: self.address,
+            _state: PhantomData,
+        }
+    }
+}
+
+impl Connection<Connected> {
+    /// Send `msg`, returning a description of what was sent.
+    pub fn send(&self, msg: &str) -> String {
+        format!("sent {:?} to {}", msg, self.address)
+    }
+
+    /// Close the connection, getting back one which could be reopened.
+    pub fn disconnect(self) -> Connection<Disconnected> {
+        Connection {
+            address: self.address,
+            _state: PhantomData,
+        }
+    }
+}
+
+impl<State> Connection<State> {
+    /// The address, which is available whatever the state.
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::mem::size_of;
+
+    #[test]
+    fn connect_then_send() {
+        let connection = Connection::new("example.com:80").connect();
+        assert_eq!(connection.send("hello"), "sent \"hello\" to example.com:80");
+        assert_eq!(connection.send("again"), "sent \"again\" to example.com:80");
+    }
+
+    #[test]
+    fn reconnect() {
+        let connection = Connection::new("example.com:80")
+            .connect()
+            .disconnect()
+            .connect();
+        assert_eq!(connection.address(), "example.com:80");
+        assert_eq!(connection.send("back"), "sent \"back\" to example.com:80");
+    }
+
+    #[test]
+    fn states_are_free() {
+        assert_eq!(size_of::<Connection<Connected>>(), size_of::<String>());
+        assert_eq!(size_of::<Connection<Disconnected>>(), size_of::<String>());
+    }
+}
@@ -0,0 +1,83 @@
+//! Phantom types: tracking units in the type system with `PhantomData`.
+//!
+//! See [e021] for more on wrapping types to make them mean more.
+//!
+//! [e021]: /show_notes/e021/index.html
+
+use std::marker::PhantomData;
+use std::ops::Add;
+
+/// Marker type for lengths.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Meters;
+
+/// Marker type for durations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Seconds;
+
+/// A number tagged with a unit.
+///
+/// `Unit` doesn't appear in any of the *data*, and the compiler rejects type
+/// parameters which go unused. `PhantomData<Unit>` is how we tell it "act as
+/// though this struct holds a `Unit`". It takes up no space at all---a
+/// `Quantity<Meters>` is exactly the size of an `f64`---so the unit checking
+/// happens entirely at compile time and costs nothing at run time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quantity<Unit> {
+    pub value: f64,
+    _marker: PhantomData<Unit>,
+}
+
+impl<Unit> Quantity<Unit> {
+    pub fn new(value: f64) -> Quantity<Unit> {
+        Quantity {
+            value,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Quantities can only be added when their units match.
+///
+/// Because this implements `Add<Quantity<Unit>>` for `Quantity<Unit>`, with
+/// the *same* `Unit` on both sides, there's simply no implementation for
+/// adding meters to seconds:
+///
+/// ```compile_fail
+/// use show_notes::examples::units::{Meters, Quantity, Seconds};
+///
+/// let distance: Quantity<Meters> = Quantity::new(100.0);
+/// let time: Quantity<Seconds> = Quantity::new(9.58);
+/// let nonsense = distance + time; // error[E0308]: mismatched types
+/// ```
+impl<Unit> Add for Quantity<Unit> {
+    type Output = Quantity<Unit>;
+
+    fn add(self, other: Quantity<Unit>) -> Quantity<Unit> {
+        Quantity::new(self.value + other.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::mem::size_of;
+
+    #[test]
+    fn add_meters() {
+        let a: Quantity<Meters> = Quantity::new(1.5);
+        let b: Quantity<Meters> = Quantity::new(2.0);
+        assert_eq!((a + b).value, 3.5);
+    }
+
+    #[test]
+    fn add_seconds() {
+        let a: Quantity<Seconds> = Quantity::new(60.0);
+        assert_eq!((a + a + a).value, 180.0);
+    }
+
+    #[test]
+    fn zero_cost() {
+        assert_eq!(size_of::<Quantity<Meters>>(), size_of::<f64>());
+    }
+}
@@ -0,0 +1,104 @@
+//! Operator overloading with the `std::ops` traits.
+//!
+//! `a + b` is just sugar for `Add::add(a, b)`, so implementing `Add` for our
+//! own type is all it takes to make `+` work with it. The same goes for `-`
+//! (`Sub`), `*` (`Mul`), and friends.
+
+use std::ops::{Add, Mul, Sub};
+
+/// A two-dimensional vector.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vector2D {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Vector2D {
+    pub fn new(x: f64, y: f64) -> Vector2D {
+        Vector2D { x, y }
+    }
+
+    /// The length of the vector.
+    pub fn magnitude(&self) -> f64 {
+        self.x.hypot(self.y)
+    }
+}
+
+impl Add for Vector2D {
+    type Output = Vector2D;
+
+    fn add(self, other: Vector2D) -> Vector2D {
+        Vector2D::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vector2D {
+    type Output = Vector2D;
+
+    fn sub(self, other: Vector2D) -> Vector2D {
+        Vector2D::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+/// Scalar multiplication: `Vector2D * f64`.
+///
+/// `Mul<f64> for Vector2D` only covers the case where the vector is on the
+/// *left*. Writing `2.0 * v` calls `<f64 as Mul<Vector2D>>::mul`, which is a
+/// different impl entirely---see below. Nothing makes the two agree except
+/// us writing both.
+impl Mul<f64> for Vector2D {
+    type Output = Vector2D;
+
+    fn mul(self, scalar: f64) -> Vector2D {
+        Vector2D::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+/// Scalar multiplication the other way around: `f64 * Vector2D`.
+///
+/// We're allowed to implement a standard-library trait (`Mul`) for a
+/// standard-library type (`f64`) here only because our own type appears as
+/// the trait's type parameter.
+impl Mul<Vector2D> for f64 {
+    type Output = Vector2D;
+
+    fn mul(self, vector: Vector2D) -> Vector2D {
+        vector * self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "{} is not close to {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn addition_and_subtraction() {
+        let a = Vector2D::new(1.0, 2.0);
+        let b = Vector2D::new(3.0, -5.0);
+        assert_eq!(a + b, Vector2D::new(4.0, -3.0));
+        assert_eq!(a - b, Vector2D::new(-2.0, 7.0));
+    }
+
+    #[test]
+    fn scalar_multiplication() {
+        let v = Vector2D::new(1.5, -2.0);
+        assert_eq!(v * 2.0, Vector2D::new(3.0, -4.0));
+        assert_eq!(2.0 * v, v * 2.0);
+    }
+
+    #[test]
+    fn magnitude() {
+        assert_close(Vector2D::new(3.0, 4.0).magnitude(), 5.0);
+        assert_close(Vector2D::new(1.0, 1.0).magnitude(), 2.0_f64.sqrt());
+        assert_close(Vector2D::new(0.0, 0.0).magnitude(), 0.0);
+    }
+}
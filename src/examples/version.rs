@@ -0,0 +1,100 @@
+//! Custom ordering with `Ord` and `PartialOrd`.
+
+use std::cmp::Ordering;
+
+/// A semantic version number.
+///
+/// (`#[derive(PartialOrd, Ord)]` would actually do the same thing here, since
+/// it compares fields in declaration order---but writing it out shows how, and
+/// the derived version silently changes meaning if someone reorders the
+/// fields.)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl Version {
+    pub fn new(major: u32, minor: u32, patch: u32) -> Version {
+        Version {
+            major,
+            minor,
+            patch,
+        }
+    }
+}
+
+impl Ord for Version {
+    /// Compare by major, then minor, then patch.
+    ///
+    /// `Ordering::then` returns `self` unless it's `Equal`, in which case it
+    /// returns its argument: so each comparison only matters when all the ones
+    /// before it were ties. That reads exactly like the rule itself. (There's
+    /// also `then_with`, which takes a closure, if the later comparisons are
+    /// expensive enough that you want to skip them when possible.)
+    fn cmp(&self, other: &Version) -> Ordering {
+        self.major
+            .cmp(&other.major)
+            .then(self.minor.cmp(&other.minor))
+            .then(self.patch.cmp(&other.patch))
+    }
+}
+
+/// `PartialOrd` must agree with `Ord`, so we just defer to it.
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Version) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Get the newest version in the list, if there is one.
+pub fn latest(versions: &[Version]) -> Option<&Version> {
+    versions.iter().max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorts() {
+        let mut versions = vec![
+            Version::new(1, 10, 0),
+            Version::new(0, 9, 9),
+            Version::new(1, 2, 3),
+            Version::new(1, 2, 10),
+            Version::new(2, 0, 0),
+        ];
+        versions.sort();
+        assert_eq!(
+            versions,
+            vec![
+                Version::new(0, 9, 9),
+                Version::new(1, 2, 3),
+                Version::new(1, 2, 10),
+                Version::new(1, 10, 0),
+                Version::new(2, 0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn picks_latest() {
+        let versions = [
+            Version::new(1, 0, 0),
+            Version::new(1, 0, 1),
+            Version::new(0, 99, 99),
+        ];
+        assert_eq!(latest(&versions), Some(&Version::new(1, 0, 1)));
+        assert_eq!(latest(&[]), None);
+    }
+
+    #[test]
+    fn equal_versions() {
+        let a = Version::new(1, 2, 3);
+        let b = Version::new(1, 2, 3);
+        assert_eq!(a.cmp(&b), Ordering::Equal);
+        assert_eq!(latest(&[a, b]), Some(&a));
+    }
+}
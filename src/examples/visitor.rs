@@ -0,0 +1,155 @@
+//! The visitor pattern, over the [`expr`] example's `Expr`.
+//!
+//! In object-oriented languages, a visitor relies on *double dispatch*: the
+//! node's `accept` method is picked by the node's runtime class, and it calls
+//! back into the visitor method picked by the visitor's. Two virtual calls
+//! together select behavior based on both types. In Rust, we don't need the
+//! first half: `walk` just `match`es on the enum to find out which variant it
+//! has, and calls the matching visitor method, which is selected by the
+//! visitor's type through the `Visitor` trait. The traversal is written once,
+//! in `walk`, and each visitor only says what to do at each kind of node.
+//!
+//! [`expr`]: ../expr/index.html
+
+use crate::examples::expr::Expr;
+
+/// Something which wants to be told about each node in an `Expr`.
+pub trait Visitor {
+    /// Called for each number.
+    fn visit_num(&mut self, n: f64);
+    /// Called for each addition, after both of its operands.
+    fn visit_add(&mut self);
+    /// Called for each multiplication, after both of its operands.
+    fn visit_mul(&mut self);
+}
+
+/// Walk over `e`, calling the visitor's method for each node.
+///
+/// The walk is *post-order*: an operator's operands are visited before the
+/// operator itself, so the sequence of calls is the expression in reverse
+/// Polish notation. That's why the operator methods don't need any
+/// arguments---by the time they're called, the visitor has already seen
+/// everything they apply to.
+pub fn walk(e: &Expr, v: &mut impl Visitor) {
+    match e {
+        Expr::Num(n) => v.visit_num(*n),
+        Expr::Add(lhs, rhs) => {
+            walk(lhs, v);
+            walk(rhs, v);
+            v.visit_add();
+        }
+        Expr::Mul(lhs, rhs) => {
+            walk(lhs, v);
+            walk(rhs, v);
+            v.visit_mul();
+        }
+    }
+}
+
+/// A visitor which counts each kind of node.
+#[derive(Debug, Default, PartialEq)]
+pub struct CountingVisitor {
+    pub nums: usize,
+    pub adds: usize,
+    pub muls: usize,
+}
+
+impl CountingVisitor {
+    /// The total number of nodes.
+    pub fn total(&self) -> usize {
+        self.nums + self.adds + self.muls
+    }
+}
+
+impl Visitor for CountingVisitor {
+    fn visit_num(&mut self, _n: f64) {
+        self.nums += 1;
+    }
+
+    fn visit_add(&mut self) {
+        self.adds += 1;
+    }
+
+    fn visit_mul(&mut self) {
+        self.muls += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::expr::eval;
+
+    fn num(n: f64) -> Box<Expr> {
+        Box::new(Expr::Num(n))
+    }
+
+    /// `(1 + 2) * (3 + 4 * 5)`
+    fn example() -> Expr {
+        Expr::Mul(
+            Box::new(Expr::Add(num(1.0), num(2.0))),
+            Box::new(Expr::Add(num(3.0), Box::new(Expr::Mul(num(4.0), num(5.0))))),
+        )
+    }
+
+    #[test]
+    fn counts_nodes() {
+        let mut counter = CountingVisitor::default();
+        walk(&example(), &mut counter);
+        assert_eq!(
+            counter,
+            CountingVisitor {
+                nums: 5,
+                adds: 2,
+                muls: 2,
+            }
+        );
+        assert_eq!(counter.total(), 9);
+    }
+
+    #[test]
+    fn counts_a_single_number() {
+        let mut counter = CountingVisitor::default();
+        walk(&Expr::Num(1.0), &mut counter);
+        assert_eq!(counter.total(), 1);
+        assert_eq!(counter.nums, 1);
+    }
+
+    /// A second visitor, to show the same `walk` driving different behavior:
+    /// post-order is exactly what a stack machine needs.
+    struct StackEvaluator {
+        stack: Vec<f64>,
+    }
+
+    impl StackEvaluator {
+        fn pop_two(&mut self) -> (f64, f64) {
+            let rhs = self.stack.pop().unwrap();
+            let lhs = self.stack.pop().unwrap();
+            (lhs, rhs)
+        }
+    }
+
+    impl Visitor for StackEvaluator {
+        fn visit_num(&mut self, n: f64) {
+            self.stack.push(n);
+        }
+
+        fn visit_add(&mut self) {
+            let (lhs, rhs) = self.pop_two();
+            self.stack.push(lhs + rhs);
+        }
+
+        fn visit_mul(&mut self) {
+            let (lhs, rhs) = self.pop_two();
+            self.stack.push(lhs * rhs);
+        }
+    }
+
+    #[test]
+    fn evaluates_like_eval() {
+        let mut evaluator = StackEvaluator { stack: Vec::new() };
+        walk(&example(), &mut evaluator);
+        assert_eq!(evaluator.stack, vec![eval(&example())]);
+        assert_eq!(evaluator.stack, vec![69.0]);
+    }
+}
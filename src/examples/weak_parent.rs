@@ -0,0 +1,88 @@
+//! Parent pointers with `Weak`, so a tree doesn't leak.
+//!
+//! The [`shared_tree`] example only points *down* the tree. To point back up
+//! as well, the obvious move is an `Rc` to the parent---but then a parent and
+//! child each keep the other's count above zero, and when the rest of the
+//! program lets go of them, neither one is ever freed. `Rc` can't detect
+//! cycles like that. A `Weak` pointer (which [e015] introduces) is the way out:
+//! it doesn't count toward keeping the value alive, so ownership only flows
+//! downward, from parents to their children. The cost is that a `Weak` might
+//! outlive its target, so we have to `upgrade` it to an `Rc` to use it, and
+//! that gives us an `Option`: `None` once the target is gone.
+//!
+//! [`shared_tree`]: ../shared_tree/index.html
+//! [e015]: /show_notes/e015/index.html
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+/// A node in a tree, which knows its parent.
+///
+/// Both links are in `RefCell`s because they get filled in after the node is
+/// created and shared, when there's no longer any `&mut` access to it.
+#[derive(Debug)]
+pub struct Node {
+    pub value: i32,
+    pub parent: RefCell<Weak<Node>>,
+    pub children: RefCell<Vec<Rc<Node>>>,
+}
+
+impl Node {
+    /// Create a new node, with no parent or children yet.
+    pub fn new(value: i32) -> Rc<Node> {
+        Rc::new(Node {
+            value,
+            parent: RefCell::new(Weak::new()),
+            children: RefCell::new(Vec::new()),
+        })
+    }
+}
+
+/// Make `child` a child of `parent`, linking them in both directions.
+pub fn add_child(parent: &Rc<Node>, child: &Rc<Node>) {
+    *child.parent.borrow_mut() = Rc::downgrade(parent);
+    parent.children.borrow_mut().push(Rc::clone(child));
+}
+
+/// The value of `node`'s parent, if it has one which still exists.
+pub fn parent_value(node: &Rc<Node>) -> Option<i32> {
+    node.parent.borrow().upgrade().map(|parent| parent.value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_parent() {
+        assert_eq!(parent_value(&Node::new(1)), None);
+    }
+
+    #[test]
+    fn upgrades_while_the_parent_lives() {
+        let parent = Node::new(1);
+        let child = Node::new(2);
+        add_child(&parent, &child);
+
+        assert_eq!(parent_value(&child), Some(1));
+        // The child's `Weak` doesn't count as an owner of the parent...
+        assert_eq!(Rc::strong_count(&parent), 1);
+        assert_eq!(Rc::weak_count(&parent), 1);
+        // ...but the parent's `Rc` does count as an owner of the child.
+        assert_eq!(Rc::strong_count(&child), 2);
+    }
+
+    #[test]
+    fn none_after_the_parent_is_dropped() {
+        let child = Node::new(2);
+        {
+            let parent = Node::new(1);
+            add_child(&parent, &child);
+            assert_eq!(parent_value(&child), Some(1));
+        }
+        // With no strong references left, the parent was freed, even though
+        // the child still points to it.
+        assert_eq!(parent_value(&child), None);
+        assert_eq!(Rc::strong_count(&child), 1);
+    }
+}
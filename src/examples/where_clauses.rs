@@ -0,0 +1,61 @@
+//! Trait bounds in `where` clauses.
+//!
+//! `fn describe<T: Debug + Clone>(items: &[T])` and the version below mean
+//! exactly the same thing; `where` just moves the bounds out of the way. With
+//! one short bound, inline is fine. But once there are several type
+//! parameters, each with a few bounds---or bounds on things which aren't bare
+//! type parameters, like `Vec<T>: Debug` or `T::Item: Clone`, which *only*
+//! `where` can express---the signature becomes hard to read, and `where` lets
+//! the name, parameters, and return type stay on one line.
+
+use std::fmt::Debug;
+
+/// Describe the first of `items`, and then all of them.
+///
+/// Returns `"<empty>"` if there aren't any.
+pub fn describe<T>(items: &[T]) -> String
+where
+    T: Debug + Clone,
+{
+    match items.first() {
+        Some(first) => {
+            let first: T = first.clone();
+            format!("first: {:?}, all: {:?}", first, items)
+        }
+        None => String::from("<empty>"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The fields are only ever read through the `Debug` impl, which the dead
+    // code lint doesn't count.
+    #[allow(dead_code)]
+    #[derive(Debug, Clone)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[test]
+    fn integers() {
+        assert_eq!(describe(&[3, 1, 4]), "first: 3, all: [3, 1, 4]");
+    }
+
+    #[test]
+    fn custom_struct() {
+        let points = [Point { x: 1, y: 2 }, Point { x: -3, y: 0 }];
+        assert_eq!(
+            describe(&points),
+            "first: Point { x: 1, y: 2 }, all: [Point { x: 1, y: 2 }, Point { x: -3, y: 0 }]"
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let none: [i32; 0] = [];
+        assert_eq!(describe(&none), "<empty>");
+    }
+}
@@ -0,0 +1,55 @@
+//! Counting words with a `HashMap`.
+
+use std::collections::HashMap;
+
+/// Count how many times each word appears in `text`.
+///
+/// Words are split on whitespace, lowercased, and have any trailing
+/// punctuation stripped, so `"Hello,"` and `"hello"` count as the same word.
+pub fn word_count(text: &str) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for raw in text.split_whitespace() {
+        let word = raw
+            .trim_end_matches(|c: char| c.is_ascii_punctuation())
+            .to_lowercase();
+        if word.is_empty() {
+            continue;
+        }
+
+        // `entry` looks the key up once, and hands back a handle to the slot.
+        // `or_insert(0)` fills that slot with `0` if it was empty, and either
+        // way gives us a `&mut usize` to the count, which we can bump in
+        // place. No separate "is it there yet?" check required!
+        *counts.entry(word).or_insert(0) += 1;
+    }
+
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert!(word_count("").is_empty());
+        assert!(word_count("   \n\t ").is_empty());
+    }
+
+    #[test]
+    fn repeated_words() {
+        let counts = word_count("the cat and the hat and the bat");
+        assert_eq!(counts["the"], 3);
+        assert_eq!(counts["and"], 2);
+        assert_eq!(counts["cat"], 1);
+        assert_eq!(counts.len(), 5);
+    }
+
+    #[test]
+    fn mixed_case_and_punctuation() {
+        let counts = word_count("Hello, hello! HELLO... world?");
+        assert_eq!(counts["hello"], 3);
+        assert_eq!(counts["world"], 1);
+        assert_eq!(counts.len(), 2);
+    }
+}
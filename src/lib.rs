@@ -58,6 +58,7 @@ pub mod e027;
 
 pub mod bonus;
 pub mod cysk;
+pub mod examples;
 pub mod interview;
 pub mod meta;
 pub mod news;
@@ -0,0 +1,26 @@
+//! Integration tests for [e007](/show_notes/e007/index.html).
+//!
+//! Everything in the `tests` directory is compiled as its own, separate crate,
+//! which links against `show_notes` exactly the way any other consumer of the
+//! library would. That means these tests can only see the library's *public*
+//! interface: we can call `show_notes::e007::add`, but not the private
+//! `support_function` in `e007`'s `tests` module---that isn't even compiled
+//! into the library, and even if it were, it isn't `pub`. Try adding
+//! `use show_notes::e007::tests::support_function;` and see what the compiler
+//! tells you!
+//!
+//! Note as well that we don't need `#[cfg(test)]` here: Cargo only builds the
+//! files in `tests` when we run `cargo test`.
+
+use show_notes::e007::add;
+
+#[test]
+fn add_from_the_outside() {
+    assert_eq!(add(2.0, 2.0), 4.0);
+}
+
+#[test]
+fn add_as_a_consumer_would_use_it() {
+    let total = [1.0, 2.0, 3.0, 4.0].iter().fold(0.0, |sum, &n| add(sum, n));
+    assert_eq!(total, 10.0);
+}